@@ -0,0 +1,489 @@
+//! Download many files concurrently.
+//!
+//! Each downloader of a batch is handled by `download_to` or `pull` on a pool of worker
+//! threads. Results are returned per downloader in the order they were given, so a single
+//! failed season does not lose the others.
+//!
+//! `NflreadClient::download_all` and `NflreadClient::pull_all` run a batch with a client,
+//! reporting the finished downloaders to its progress hook, see the `progress` module.
+use crate::client::{self, NflreadClient};
+use crate::downloader::{Downloader, stack};
+use crate::error::{Error, Result};
+use crate::progress::BatchProgress;
+use polars::prelude::DataFrame;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use url::Url;
+
+/// Number of downloads running at the same time if not set otherwise.
+pub const DEFAULT_WORKERS: usize = 4;
+
+/// Downloads the data of many downloaders concurrently, see `download_to`.
+///
+/// Returns one result per downloader in the same order.
+///
+/// # Arguments
+///
+/// * `downloaders` -   The structs relating to the desired stats. Need to implement Downloader.
+/// * `dir`         -   Destination directory, created if missing. If none, the files will be downloaded to the temporary dictionary.
+/// * `force`       -   Overwrites exiting files if true. If None or false the results of existing files are `Error::FileExists`.
+/// * `workers`     -   Maximum number of concurrent downloads, `DEFAULT_WORKERS` if None.
+///
+/// Downloaders spanning a single file are saved under the file name of their URL, e.g.
+/// `play_by_play_2024.csv`. If the downloader filters rows or another downloader of the
+/// batch has the same file, its index in the batch is added, e.g. `ngs_passing_1.csv`.
+/// Downloaders spanning several files are stacked into one file with a UUID based name.
+///
+/// # Examples
+///
+/// ```no_run
+/// use nflreadrs::batch::download_all;
+/// use nflreadrs::stats::PlayByPlay;
+///
+/// let downloaders: Vec<PlayByPlay> = (2000..=2024)
+///     .map(|season| PlayByPlay::new(Some(vec![season])))
+///     .collect();
+///
+/// for result in download_all(&downloaders, None, None, Some(8)) {
+///     match result {
+///         Ok(path) => println!("downloaded {}", path.display()),
+///         Err(e) => eprintln!("{}", e),
+///     }
+/// }
+/// ```
+pub fn download_all<D>(
+    downloaders: &[D],
+    dir: Option<&PathBuf>,
+    force: Option<bool>,
+    workers: Option<usize>,
+) -> Vec<Result<PathBuf>>
+where
+    D: Downloader + Sync,
+{
+    match client::default_client() {
+        Ok(client) => download_all_from(&client, downloaders, dir, force, workers),
+        Err(e) => unstarted(downloaders.len(), e),
+    }
+}
+
+/// Downloads the data of many downloaders concurrently with a client, see `download_all`.
+pub(crate) fn download_all_from<D>(
+    client: &NflreadClient,
+    downloaders: &[D],
+    dir: Option<&PathBuf>,
+    force: Option<bool>,
+    workers: Option<usize>,
+) -> Vec<Result<PathBuf>>
+where
+    D: Downloader + Sync,
+{
+    let report = |progress: &BatchProgress| client.report_batch(progress);
+    let shared = shared_names(downloaders);
+    let jobs: Vec<(usize, &D)> = downloaders.iter().enumerate().collect();
+
+    run(&jobs, workers, report, |(index, downloader)| {
+        let path = match dir {
+            Some(dir) => Some(file_path(*downloader, dir, *index, &shared)?),
+            None => None,
+        };
+
+        client.download_to(*downloader, path.as_ref(), force)
+    })
+}
+
+/// Pulls the data of many downloaders concurrently, see `pull`.
+///
+/// Returns one result per downloader in the same order, use `stack_successful`
+/// to combine them into a single DataFrame.
+///
+/// # Arguments
+///
+/// * `downloaders` -   The structs relating to the desired stats. Need to implement Downloader.
+/// * `workers`     -   Maximum number of concurrent downloads, `DEFAULT_WORKERS` if None.
+pub fn pull_all<D>(downloaders: &[D], workers: Option<usize>) -> Vec<Result<DataFrame>>
+where
+    D: Downloader + Sync,
+{
+    match client::default_client() {
+        Ok(client) => pull_all_from(&client, downloaders, workers),
+        Err(e) => unstarted(downloaders.len(), e),
+    }
+}
+
+/// Pulls the data of many downloaders concurrently with a client, see `pull_all`.
+pub(crate) fn pull_all_from<D>(
+    client: &NflreadClient,
+    downloaders: &[D],
+    workers: Option<usize>,
+) -> Vec<Result<DataFrame>>
+where
+    D: Downloader + Sync,
+{
+    let report = |progress: &BatchProgress| client.report_batch(progress);
+
+    run(downloaders, workers, report, |downloader| {
+        client.pull(downloader)
+    })
+}
+
+/// Stacks the successfully pulled DataFrames of a batch into one, skipping failures.
+///
+/// Schemas are aligned like for multi-season downloaders, see `pull`.
+pub fn stack_successful(results: Vec<Result<DataFrame>>) -> Result<DataFrame> {
+    stack(results.into_iter().filter_map(Result::ok).collect())
+}
+
+/// Results of a batch whose client could not be built, an error for every downloader.
+///
+/// The first downloader gets the error itself, the others its message.
+fn unstarted<T>(downloaders: usize, error: Error) -> Vec<Result<T>> {
+    let message = error.to_string();
+    let mut results = Vec::with_capacity(downloaders);
+
+    if downloaders > 0 {
+        results.push(Err(error));
+    }
+    results.extend((1..downloaders).map(|_| Err(io::Error::other(message.clone()).into())));
+
+    results
+}
+
+/// Destination of a downloader inside a directory, see `download_all` for the names.
+///
+/// The directory is created if it does not exist.
+///
+/// # Arguments
+///
+/// * `index`   -   Index of the downloader in the batch.
+/// * `shared`  -   File names used by more than one downloader of the batch, see `shared_names`.
+fn file_path<D>(
+    downloader: &D,
+    dir: &Path,
+    index: usize,
+    shared: &HashSet<String>,
+) -> Result<PathBuf>
+where
+    D: Downloader,
+{
+    let urls = downloader.urls()?;
+    fs::create_dir_all(dir)?;
+
+    Ok(match file_name(&urls) {
+        Some(name) if downloader.filter().is_none() && !shared.contains(name) => dir.join(name),
+        Some(name) => match name.split_once('.') {
+            Some((stem, extension)) => dir.join(format!("{}_{}.{}", stem, index, extension)),
+            None => dir.join(format!("{}_{}", name, index)),
+        },
+        None => dir.to_path_buf(),
+    })
+}
+
+/// File name of the URL of a single file downloader, None for several files.
+fn file_name(urls: &[Url]) -> Option<&str> {
+    match urls {
+        [url] => url
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .filter(|name| !name.is_empty()),
+        _ => None,
+    }
+}
+
+/// File names used by more than one downloader of a batch.
+fn shared_names<D>(downloaders: &[D]) -> HashSet<String>
+where
+    D: Downloader,
+{
+    let mut seen = HashSet::new();
+    let mut shared = HashSet::new();
+
+    for downloader in downloaders {
+        let Ok(urls) = downloader.urls() else {
+            continue;
+        };
+        if let Some(name) = file_name(&urls)
+            && !seen.insert(name.to_string())
+        {
+            shared.insert(name.to_string());
+        }
+    }
+
+    shared
+}
+
+/// Runs a task for every downloader on at most `workers` threads.
+///
+/// At least one thread is used. Results are ordered like the downloaders.
+/// Progress is reported after every finished task.
+fn run<D, T, R, F>(downloaders: &[D], workers: Option<usize>, report: R, task: F) -> Vec<Result<T>>
+where
+    D: Sync,
+    T: Send,
+    R: Fn(&BatchProgress) + Sync,
+    F: Fn(&D) -> Result<T> + Sync,
+{
+    let workers = workers
+        .unwrap_or(DEFAULT_WORKERS)
+        .clamp(1, downloaders.len().max(1));
+    let next = AtomicUsize::new(0);
+    let completed = AtomicUsize::new(0);
+    let failed = AtomicUsize::new(0);
+
+    let mut results: Vec<Option<Result<T>>> = downloaders.iter().map(|_| None).collect();
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(downloader) = downloaders.get(index) else {
+                            break;
+                        };
+                        let result = task(downloader);
+
+                        if result.is_err() {
+                            failed.fetch_add(1, Ordering::SeqCst);
+                        }
+                        report(&BatchProgress {
+                            completed: completed.fetch_add(1, Ordering::SeqCst) + 1,
+                            failed: failed.load(Ordering::SeqCst),
+                            total: downloaders.len(),
+                        });
+
+                        done.push((index, result));
+                    }
+                    done
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let done = handle.join().unwrap_or_else(|e| panic::resume_unwind(e));
+            for (index, result) in done {
+                results[index] = Some(result);
+            }
+        }
+    });
+
+    results.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::progress::Progress;
+    use crate::stats::{NextGenStats, NgsStatType, PlayByPlay};
+    use crate::test_server::{Response, TestServer};
+    use crate::test_support::{UrlDownloader, test_dir};
+    use polars::df;
+    use reqwest::StatusCode;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[test]
+    fn test_download_all_keeps_order_and_failures() {
+        let server = TestServer::start(|request| match request.path.as_str() {
+            "/pbp/play_by_play_2030.csv" => Response::status(404),
+            path => Response::ok(format!("path\n{}\n", path)),
+        });
+        let dir = test_dir();
+        let downloaders: Vec<UrlDownloader> = [2023, 2030, 2024]
+            .iter()
+            .map(|season| UrlDownloader(server.url(&format!("/pbp/play_by_play_{}.csv", season))))
+            .collect();
+
+        let results = download_all(&downloaders, Some(&dir), None, Some(2));
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().unwrap(),
+            &dir.join("play_by_play_2023.csv")
+        );
+        assert!(
+            matches!(&results[1], Err(Error::Http { status, .. }) if *status == StatusCode::NOT_FOUND)
+        );
+        assert_eq!(
+            fs::read_to_string(results[2].as_ref().unwrap()).unwrap(),
+            "path\n/pbp/play_by_play_2024.csv\n"
+        );
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_download_all_same_file() {
+        let source = test_dir();
+        fs::create_dir_all(source.join("nextgen_stats")).unwrap();
+        fs::write(
+            source.join("nextgen_stats/ngs_passing.csv"),
+            "season,week\n2023,0\n2024,0\n2024,1\n",
+        )
+        .unwrap();
+        let client = NflreadClient::builder()
+            .with_base_url(crate::source::dir_url(&source).unwrap())
+            .build()
+            .unwrap();
+        let dir = test_dir();
+        let downloaders: Vec<NextGenStats> = [2023, 2024]
+            .iter()
+            .map(|season| NextGenStats::new(Some(vec![*season]), NgsStatType::Passing))
+            .collect();
+
+        let results = client.download_all(&downloaders, Some(&dir), None, Some(2));
+
+        let paths: Vec<PathBuf> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(
+            paths,
+            [dir.join("ngs_passing_0.csv"), dir.join("ngs_passing_1.csv")]
+        );
+        assert_eq!(
+            fs::read_to_string(&paths[0]).unwrap(),
+            "season,week\n2023,0\n"
+        );
+        assert_eq!(
+            fs::read_to_string(&paths[1]).unwrap(),
+            "season,week\n2024,0\n2024,1\n"
+        );
+
+        let server = TestServer::start(|_| Response::ok("season\n2024\n"));
+        let url = server.url("/pbp/play_by_play_2024.csv");
+        let downloaders = [UrlDownloader(url.clone()), UrlDownloader(url)];
+
+        let results = download_all(&downloaders, Some(&dir), None, None);
+
+        assert_eq!(
+            results[1].as_ref().unwrap(),
+            &dir.join("play_by_play_2024_1.csv")
+        );
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 4);
+
+        fs::remove_dir_all(source).unwrap();
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_download_all_missing_dir() {
+        let source = test_dir();
+        fs::create_dir_all(source.join("pbp")).unwrap();
+        for season in [2023, 2024] {
+            fs::write(
+                source.join(format!("pbp/play_by_play_{}.csv", season)),
+                format!("season\n{}\n", season),
+            )
+            .unwrap();
+        }
+        let client = NflreadClient::builder()
+            .with_base_url(crate::source::dir_url(&source).unwrap())
+            .build()
+            .unwrap();
+        let dir = test_dir().join("missing");
+        let downloaders = [
+            PlayByPlay::new(Some(vec![2024])),
+            PlayByPlay::new(Some(vec![2023, 2024])),
+        ];
+
+        let results = client.download_all(&downloaders, Some(&dir), None, None);
+
+        let paths: Vec<PathBuf> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(paths[0], dir.join("play_by_play_2024.csv"));
+        assert_eq!(paths[1].parent(), Some(dir.as_path()));
+        assert_eq!(
+            fs::read_to_string(&paths[1]).unwrap(),
+            "season\n2023\n2024\n"
+        );
+
+        fs::remove_dir_all(source).unwrap();
+        fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_reports_batch_progress() {
+        /// Progress hook recording batch progress.
+        struct Recorder(Arc<Mutex<Vec<BatchProgress>>>);
+
+        impl Progress for Recorder {
+            fn batch(&self, progress: &BatchProgress) {
+                self.0.lock().unwrap().push(*progress);
+            }
+        }
+
+        let server = TestServer::start(|request| match request.path.as_str() {
+            "/pbp/play_by_play_2030.csv" => Response::status(404),
+            _ => Response::ok("season\n2024\n"),
+        });
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let client = NflreadClient::builder()
+            .with_progress(Recorder(Arc::clone(&events)))
+            .build()
+            .unwrap();
+        let dir = test_dir();
+        let downloaders: Vec<UrlDownloader> = [2023, 2030, 2024]
+            .iter()
+            .map(|season| UrlDownloader(server.url(&format!("/pbp/play_by_play_{}.csv", season))))
+            .collect();
+
+        client.download_all(&downloaders, Some(&dir), None, Some(2));
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events.iter().map(|p| p.completed).max(), Some(3));
+        assert_eq!(events.iter().map(|p| p.failed).max(), Some(1));
+        assert!(events.iter().all(|p| p.total == 3));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_run_bounds_workers() {
+        let active = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let downloaders: Vec<usize> = (0..8).collect();
+
+        let results = run(
+            &downloaders,
+            Some(3),
+            |_| {},
+            |n| {
+                let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                thread::sleep(Duration::from_millis(20));
+                active.fetch_sub(1, Ordering::SeqCst);
+                Ok(n * 2)
+            },
+        );
+
+        let doubled: Vec<usize> = results.into_iter().map(Result::unwrap).collect();
+        assert_eq!(doubled, (0..8).map(|n| n * 2).collect::<Vec<_>>());
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[test]
+    fn test_run_zero_workers_and_empty_batch() {
+        let results = run(&[1, 2], Some(0), |_| {}, |n| Ok(*n));
+        assert_eq!(results.len(), 2);
+
+        let results = run(&Vec::<usize>::new(), None, |_| {}, |n| Ok(*n));
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_stack_successful_skips_failures() {
+        let results = vec![
+            Ok(df!("season" => [2023]).unwrap()),
+            Err(Error::InvalidSeason("2030".to_string())),
+            Ok(df!("season" => [2024]).unwrap()),
+        ];
+
+        let stacked = stack_successful(results).unwrap();
+
+        assert_eq!(stacked.shape(), (2, 1));
+    }
+}
@@ -0,0 +1,560 @@
+//! Persistent on-disk cache of downloaded files.
+//!
+//! Pulled files are stored in the cache directory keyed by their URL and reused
+//! until they are older than the maximum age. Next to each file a small metadata
+//! file records where and when it was downloaded along with the `ETag` and
+//! `Last-Modified` response headers. Expired entries are revalidated with a
+//! conditional request and only downloaded again if they changed.
+use crate::downloader::write_atomic;
+use crate::error::{Error, Result};
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use url::Url;
+
+/// Environment variable to set the cache directory.
+pub const CACHE_DIR_ENV: &str = "NFLREADRS_CACHE_DIR";
+
+/// Maximum age of cache entries if not set otherwise, one day.
+pub const DEFAULT_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Extension of the metadata file stored next to each cached file.
+const META_EXTENSION: &str = "meta";
+
+/// Cache settings set through the API.
+struct Settings {
+    dir: Option<PathBuf>,
+    max_age: Duration,
+    enabled: bool,
+}
+
+static SETTINGS: RwLock<Settings> = RwLock::new(Settings {
+    dir: None,
+    max_age: DEFAULT_MAX_AGE,
+    enabled: true,
+});
+
+/// Returns the cache directory.
+///
+/// The directory set by `set_cache_dir` takes precedence over the `NFLREADRS_CACHE_DIR`
+/// environment variable. If neither is set, the user's cache directory is used.
+pub fn cache_dir() -> PathBuf {
+    if let Some(dir) = &SETTINGS.read().unwrap_or_else(|e| e.into_inner()).dir {
+        return dir.clone();
+    }
+
+    match env::var_os(CACHE_DIR_ENV) {
+        Some(dir) => PathBuf::from(dir),
+        None => dirs::cache_dir()
+            .unwrap_or_else(env::temp_dir)
+            .join("nflreadrs"),
+    }
+}
+
+/// Sets the cache directory, overriding the `NFLREADRS_CACHE_DIR` environment variable.
+pub fn set_cache_dir(dir: impl Into<PathBuf>) {
+    SETTINGS.write().unwrap_or_else(|e| e.into_inner()).dir = Some(dir.into());
+}
+
+/// Returns the maximum age after which cache entries are revalidated.
+pub fn max_age() -> Duration {
+    SETTINGS.read().unwrap_or_else(|e| e.into_inner()).max_age
+}
+
+/// Sets the maximum age after which cache entries are revalidated.
+pub fn set_max_age(max_age: Duration) {
+    SETTINGS.write().unwrap_or_else(|e| e.into_inner()).max_age = max_age;
+}
+
+/// Returns whether pulled files are cached.
+pub fn is_enabled() -> bool {
+    SETTINGS.read().unwrap_or_else(|e| e.into_inner()).enabled
+}
+
+/// Enables or disables caching of pulled files. Enabled by default.
+///
+/// If disabled, files are downloaded to the temporary directory and removed after reading.
+pub fn set_enabled(enabled: bool) {
+    SETTINGS.write().unwrap_or_else(|e| e.into_inner()).enabled = enabled;
+}
+
+/// A file stored in the cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheEntry {
+    /// URL the file was downloaded from.
+    pub url: Url,
+    /// Location of the file on disk.
+    pub path: PathBuf,
+    /// Size of the file in bytes.
+    pub size: u64,
+    /// Time the file was downloaded or last revalidated.
+    pub downloaded: SystemTime,
+    /// `ETag` response header of the download.
+    pub etag: Option<String>,
+    /// `Last-Modified` response header of the download.
+    pub last_modified: Option<String>,
+}
+
+impl CacheEntry {
+    /// Time passed since the file was downloaded.
+    pub fn age(&self) -> Duration {
+        self.downloaded.elapsed().unwrap_or_default()
+    }
+
+    /// Returns true if the entry is older than the given maximum age.
+    pub fn is_expired(&self, max_age: Duration) -> bool {
+        self.age() > max_age
+    }
+}
+
+/// Returns the cache entry of a URL if it exists.
+pub fn entry(url: &Url) -> Result<Option<CacheEntry>> {
+    lookup(&cache_dir(), url)
+}
+
+/// Lists all entries of the cache.
+pub fn list() -> Result<Vec<CacheEntry>> {
+    list_in(&cache_dir())
+}
+
+/// Removes the cache entry of a URL. Returns true if an entry was removed.
+pub fn remove(url: &Url) -> Result<bool> {
+    remove_entry(&entry_path(&cache_dir(), url))
+}
+
+/// Removes all entries from the cache. Returns the number of removed entries.
+///
+/// Leftovers of interrupted downloads, files without metadata and metadata without files,
+/// are removed as well. Files not named like cache entries are kept.
+pub fn clear() -> Result<usize> {
+    clear_in(&cache_dir())
+}
+
+/// Returns the entry of a URL in a cache directory if it exists.
+///
+/// Entries recorded for another URL are ignored.
+pub(crate) fn lookup(dir: &Path, url: &Url) -> Result<Option<CacheEntry>> {
+    Ok(read_entry(&entry_path(dir, url))?.filter(|entry| entry.url == *url))
+}
+
+/// Returns the path a URL is cached at, creating the cache directory if needed.
+pub(crate) fn prepare(dir: &Path, url: &Url) -> Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+
+    Ok(entry_path(dir, url))
+}
+
+/// Records that the file of a URL was just downloaded or revalidated.
+///
+/// # Arguments
+///
+/// * `dir`             -   Cache directory.
+/// * `url`             -   URL of the cached file.
+/// * `etag`            -   `ETag` response header, if any.
+/// * `last_modified`   -   `Last-Modified` response header, if any.
+pub(crate) fn commit(
+    dir: &Path,
+    url: &Url,
+    etag: Option<&str>,
+    last_modified: Option<&str>,
+) -> Result<()> {
+    let path = entry_path(dir, url);
+    let entry = CacheEntry {
+        url: url.clone(),
+        size: fs::metadata(&path)?.len(),
+        path,
+        downloaded: SystemTime::now(),
+        etag: etag.map(String::from),
+        last_modified: last_modified.map(String::from),
+    };
+
+    write_meta(&entry)
+}
+
+/// Path of the cached file of a URL.
+///
+/// The file name is a hash of the full URL followed by the last segment of its path,
+/// keeping the file extension, e.g. `1f0c3e9a2b4d5c6e-play_by_play_2024.csv`.
+fn entry_path(dir: &Path, url: &Url) -> PathBuf {
+    let name: String = url
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .unwrap_or_default()
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' | '_' => c,
+            _ => '_',
+        })
+        .collect();
+
+    dir.join(format!("{:016x}-{}", fnv1a(url.as_str()), name))
+}
+
+/// FNV-1a hash of a string, stable across builds unlike the hashers of std.
+fn fnv1a(value: &str) -> u64 {
+    value.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Path of the metadata file of a cached file.
+fn meta_path(path: &Path) -> PathBuf {
+    let mut meta = path.as_os_str().to_owned();
+    meta.push(".");
+    meta.push(META_EXTENSION);
+    PathBuf::from(meta)
+}
+
+/// Writes the metadata of a cache entry next to its file.
+fn write_meta(entry: &CacheEntry) -> Result<()> {
+    let downloaded = entry
+        .downloaded
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let mut meta = format!("url={}\ndownloaded={}\n", entry.url, downloaded);
+
+    if let Some(etag) = &entry.etag {
+        meta.push_str(&format!("etag={}\n", etag));
+    }
+    if let Some(last_modified) = &entry.last_modified {
+        meta.push_str(&format!("last_modified={}\n", last_modified));
+    }
+
+    write_atomic(&meta_path(&entry.path), |file| {
+        Ok(file.write_all(meta.as_bytes())?)
+    })
+}
+
+/// Reads a cache entry from its metadata. Returns None if the file or its metadata is missing.
+fn read_entry(path: &Path) -> Result<Option<CacheEntry>> {
+    let meta = meta_path(path);
+    if !meta.exists() || !path.exists() {
+        return Ok(None);
+    }
+
+    let mut url = None;
+    let mut downloaded = None;
+    let mut etag = None;
+    let mut last_modified = None;
+
+    let malformed = || Error::InvalidCache(meta.clone());
+
+    for line in fs::read_to_string(&meta)?.lines() {
+        match line.split_once('=') {
+            Some(("url", value)) => url = Some(Url::parse(value).map_err(|_| malformed())?),
+            Some(("downloaded", value)) => {
+                let secs = value.parse().map_err(|_| malformed())?;
+                downloaded = Some(UNIX_EPOCH + Duration::from_secs(secs))
+            }
+            Some(("etag", value)) => etag = Some(value.to_string()),
+            Some(("last_modified", value)) => last_modified = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    let (Some(url), Some(downloaded)) = (url, downloaded) else {
+        return Err(malformed());
+    };
+
+    Ok(Some(CacheEntry {
+        url,
+        path: path.to_path_buf(),
+        size: fs::metadata(path)?.len(),
+        downloaded,
+        etag,
+        last_modified,
+    }))
+}
+
+/// Lists all entries in a cache directory.
+fn list_in(dir: &Path) -> Result<Vec<CacheEntry>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = Vec::new();
+    for file in fs::read_dir(dir)? {
+        let path = file?.path();
+        if path.extension().is_some_and(|ext| ext == META_EXTENSION)
+            && let Some(entry) = read_entry(&path.with_extension(""))?
+        {
+            entries.push(entry);
+        }
+    }
+
+    entries.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(entries)
+}
+
+/// Removes a cached file and its metadata. Returns true if the file existed.
+fn remove_entry(path: &Path) -> Result<bool> {
+    let meta = meta_path(path);
+    if meta.exists() {
+        fs::remove_file(meta)?;
+    }
+
+    if path.exists() {
+        fs::remove_file(path)?;
+        return Ok(true);
+    }
+
+    Ok(false)
+}
+
+/// Removes all entries and their leftovers in a cache directory, see `clear`.
+///
+/// Returns the number of removed cached files.
+fn clear_in(dir: &Path) -> Result<usize> {
+    if !dir.is_dir() {
+        return Ok(0);
+    }
+
+    let mut removed = 0;
+    for file in fs::read_dir(dir)? {
+        let path = file?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+
+        if name.starts_with('.') && name.ends_with(".part") && is_entry_name(&name[1..]) {
+            remove_file(&path)?;
+        } else if name.ends_with(&format!(".{}", META_EXTENSION)) && is_entry_name(name) {
+            if !path.with_extension("").exists() {
+                remove_file(&path)?;
+            }
+        } else if is_entry_name(name) && remove_entry(&path)? {
+            removed += 1;
+        }
+    }
+
+    Ok(removed)
+}
+
+/// Returns true if a file name starts like the name of a cached file, see `entry_path`.
+fn is_entry_name(name: &str) -> bool {
+    let bytes = name.as_bytes();
+
+    bytes.len() > 16 && bytes[..16].iter().all(u8::is_ascii_hexdigit) && bytes[16] == b'-'
+}
+
+/// Removes a file, ignoring that it is already gone.
+fn remove_file(path: &Path) -> Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_dir;
+
+    /// Adds a file with metadata to a cache directory.
+    fn add_entry(dir: &Path, url: &str, downloaded: SystemTime) -> PathBuf {
+        let url = Url::parse(url).unwrap();
+        let path = entry_path(dir, &url);
+        fs::write(&path, "season,team\n2024,KC\n").unwrap();
+        write_meta(&CacheEntry {
+            url,
+            path: path.clone(),
+            size: 20,
+            downloaded,
+            etag: Some("\"v1\"".to_string()),
+            last_modified: None,
+        })
+        .unwrap();
+        path
+    }
+
+    mod entry_path_tests {
+        use super::*;
+
+        #[test]
+        fn test_entry_path_keeps_extension() {
+            let cases = [
+                // (url, expected file name ending)
+                (
+                    "https://github.com/nflverse/nflverse-data/releases/download/pbp/play_by_play_2024.csv",
+                    "-play_by_play_2024.csv",
+                ),
+                (
+                    "https://github.com/nflverse/nflverse-data/releases/download/pbp/play_by_play_2024.parquet",
+                    "-play_by_play_2024.parquet",
+                ),
+                ("http://localhost:8000/", "-"),
+            ];
+
+            for (url, exp) in cases {
+                let path = entry_path(Path::new("/cache"), &Url::parse(url).unwrap());
+                let name = path.file_name().unwrap().to_str().unwrap();
+                assert_eq!(path.parent(), Some(Path::new("/cache")));
+                assert!(name.ends_with(exp), "{}", name);
+                assert_eq!(name.len(), 16 + exp.len(), "{}", name);
+            }
+        }
+
+        #[test]
+        fn test_entry_path_distinguishes_urls() {
+            let urls = [
+                "http://localhost:8000/pbp/play_by_play_2024.csv",
+                "http://localhost:9000/pbp/play_by_play_2024.csv",
+                "http://localhost:8000/pbp_play_by_play_2024.csv",
+                "http://localhost:8000/pbp/play_by_play_2024.csv?season=2024",
+            ];
+
+            let paths: std::collections::HashSet<PathBuf> = urls
+                .iter()
+                .map(|url| entry_path(Path::new("/cache"), &Url::parse(url).unwrap()))
+                .collect();
+
+            assert_eq!(paths.len(), urls.len());
+        }
+    }
+
+    mod entry_tests {
+        use super::*;
+
+        #[test]
+        fn test_read_entry_round_trip() {
+            let dir = test_dir();
+            let url = "https://example.com/rosters/roster_2024.csv";
+            let downloaded = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+            let path = add_entry(&dir, url, downloaded);
+
+            let entry = read_entry(&path).unwrap().unwrap();
+            assert_eq!(entry.url, Url::parse(url).unwrap());
+            assert_eq!(entry.size, 20);
+            assert_eq!(entry.downloaded, downloaded);
+            assert_eq!(entry.etag.as_deref(), Some("\"v1\""));
+            assert_eq!(entry.last_modified, None);
+            assert!(entry.is_expired(DEFAULT_MAX_AGE));
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_commit_updates_validators() {
+            let dir = test_dir();
+            let url = Url::parse("https://example.com/rosters/roster_2024.csv").unwrap();
+            let path = prepare(&dir, &url).unwrap();
+            fs::write(&path, "season\n2024\n").unwrap();
+
+            commit(&dir, &url, None, Some("Wed, 01 Jan 2025 00:00:00 GMT")).unwrap();
+            let entry = lookup(&dir, &url).unwrap().unwrap();
+            assert_eq!(entry.etag, None);
+            assert_eq!(
+                entry.last_modified.as_deref(),
+                Some("Wed, 01 Jan 2025 00:00:00 GMT")
+            );
+            assert!(!entry.is_expired(DEFAULT_MAX_AGE));
+
+            commit(&dir, &url, Some("W/\"a=b\""), None).unwrap();
+            let entry = lookup(&dir, &url).unwrap().unwrap();
+            assert_eq!(entry.etag.as_deref(), Some("W/\"a=b\""));
+            assert_eq!(entry.last_modified, None);
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_lookup_ignores_other_url() {
+            let dir = test_dir();
+            let url = Url::parse("https://example.com/rosters/roster_2024.csv").unwrap();
+            let path = add_entry(&dir, url.as_str(), SystemTime::now());
+            write_meta(&CacheEntry {
+                url: Url::parse("https://example.com/rosters/roster_2023.csv").unwrap(),
+                path,
+                size: 20,
+                downloaded: SystemTime::now(),
+                etag: None,
+                last_modified: None,
+            })
+            .unwrap();
+
+            assert!(lookup(&dir, &url).unwrap().is_none());
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_read_entry_missing_meta() {
+            let dir = test_dir();
+            let path = dir.join("orphan.csv");
+            fs::write(&path, "season\n2024\n").unwrap();
+
+            assert!(read_entry(&path).unwrap().is_none());
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_list_and_clear() {
+            let dir = test_dir();
+            add_entry(&dir, "https://example.com/a.csv", SystemTime::now());
+            add_entry(&dir, "https://example.com/b.parquet", SystemTime::now());
+
+            let entries = list_in(&dir).unwrap();
+            assert_eq!(entries.len(), 2);
+            assert!(
+                entries
+                    .iter()
+                    .all(|entry| !entry.is_expired(DEFAULT_MAX_AGE))
+            );
+
+            assert_eq!(clear_in(&dir).unwrap(), 2);
+            assert!(list_in(&dir).unwrap().is_empty());
+            assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_clear_removes_leftovers() {
+            let dir = test_dir();
+            let kept = add_entry(&dir, "https://example.com/a.csv", SystemTime::now());
+            let orphan = entry_path(&dir, &Url::parse("https://example.com/b.csv").unwrap());
+            fs::write(&orphan, "season\n2024\n").unwrap();
+            let lone_meta = meta_path(&entry_path(
+                &dir,
+                &Url::parse("https://example.com/c.csv").unwrap(),
+            ));
+            fs::write(&lone_meta, "url=https://example.com/c.csv\n").unwrap();
+            let part = crate::downloader::part_path(&kept);
+            fs::write(&part, "seas").unwrap();
+            fs::write(dir.join("notes.txt"), "not cached").unwrap();
+
+            assert_eq!(clear_in(&dir).unwrap(), 2);
+
+            let left: Vec<PathBuf> = fs::read_dir(&dir)
+                .unwrap()
+                .map(|file| file.unwrap().path())
+                .collect();
+            assert_eq!(left, [dir.join("notes.txt")]);
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_read_entry_malformed_meta() {
+            let dir = test_dir();
+            let path = dir.join("broken.csv");
+            fs::write(&path, "season\n2024\n").unwrap();
+            fs::write(meta_path(&path), "url=https://example.com/broken.csv\n").unwrap();
+
+            assert!(matches!(read_entry(&path), Err(Error::InvalidCache(_))));
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_list_missing_dir() {
+            let dir = env::temp_dir().join(format!("nflreadrs-test-{}", uuid::Uuid::new_v4()));
+            assert!(list_in(&dir).unwrap().is_empty());
+        }
+    }
+}
@@ -0,0 +1,798 @@
+//! Reusable HTTP client pulling data from the nflverse data host.
+//!
+//! A `NflreadClient` owns the underlying HTTP client with its connection pool along with
+//! the request options, user agent and proxy. Build one with `NflreadClient::builder` and
+//! reuse it for all downloads. The free functions of the `downloader` module use a default
+//! client built from the options set with `request::set_options`.
+//!
+//! Requests to GitHub are authenticated with a token if one is set, which raises GitHub's
+//! rate limit for anonymous requests. Like nflreadr, the token is read from the
+//! `GITHUB_PAT` or `GITHUB_TOKEN` environment variables unless set on the builder.
+//!
+//! Data is downloaded from the base URL of `source::base_url` unless the client is built
+//! with its own, e.g. to serve one client from a mirror.
+use crate::batch;
+use crate::downloader::{self, Downloader, Pulled, check_status, create_headers, is_conditional};
+use crate::error::{Error, Result};
+use crate::progress::{BatchProgress, FileProgress, Progress};
+use crate::releases::{self, ReleaseAsset};
+use crate::request::{self, RequestOptions, RetryPolicy};
+use crate::sitrep::{self, Sitrep};
+use crate::source;
+use polars::prelude::DataFrame;
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue};
+use reqwest::{Proxy, blocking};
+use serde::de::DeserializeOwned;
+use std::env;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock, RwLock};
+use std::thread;
+use std::time::Duration;
+use url::Url;
+
+/// User agent sent with all requests if not set otherwise.
+pub const DEFAULT_USER_AGENT: &str = "nflreadrs";
+
+/// Environment variables a GitHub token is read from, in order of precedence.
+pub const GITHUB_TOKEN_ENVS: [&str; 2] = ["GITHUB_PAT", "GITHUB_TOKEN"];
+
+/// Client pulling data from the nflverse data host.
+///
+/// Cloning a client is cheap and shares its connection pool. The blocking and the
+/// non-blocking HTTP clients are built on their first use, so a client only used with
+/// the async functions never creates the blocking client, which must not be dropped
+/// inside an async runtime.
+#[derive(Debug, Clone)]
+pub struct NflreadClient {
+    inner: Arc<Inner>,
+}
+
+/// Settings and lazily built HTTP clients shared by the clones of a client.
+#[derive(Debug)]
+struct Inner {
+    settings: NflreadClientBuilder,
+    proxy: Option<Proxy>,
+    authorization: Option<HeaderValue>,
+    http: OnceLock<blocking::Client>,
+    #[cfg(feature = "async")]
+    http_async: OnceLock<reqwest::Client>,
+}
+
+impl NflreadClient {
+    /// Create a client with the default settings.
+    pub fn new() -> Result<Self> {
+        NflreadClient::builder().build()
+    }
+
+    /// Create a builder to configure a client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nflreadrs::client::NflreadClient;
+    /// use nflreadrs::request::{RequestOptions, RetryPolicy};
+    ///
+    /// let client = NflreadClient::builder()
+    ///     .with_user_agent("my-dashboard/1.0")
+    ///     .with_options(RequestOptions::new().with_retry(RetryPolicy::new().with_max_attempts(5)))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// # assert_eq!(client.options().retry().max_attempts(), 5);
+    /// ```
+    pub fn builder() -> NflreadClientBuilder {
+        NflreadClientBuilder::default()
+    }
+
+    /// Request options of the client.
+    pub fn options(&self) -> &RequestOptions {
+        &self.inner.settings.options
+    }
+
+    /// Base URL the client downloads data from.
+    ///
+    /// The base URL set on the builder, `source::base_url` if not set.
+    pub fn base_url(&self) -> Result<Url> {
+        match &self.inner.settings.base_url {
+            Some(base_url) => Ok(base_url.clone()),
+            None => source::base_url(),
+        }
+    }
+
+    /// GitHub API of the nflverse-data repository, `source::DEFAULT_API_URL` if not set.
+    pub fn api_url(&self) -> Result<Url> {
+        match &self.inner.settings.api_url {
+            Some(api_url) => Ok(api_url.clone()),
+            None => Ok(Url::parse(source::DEFAULT_API_URL)?),
+        }
+    }
+
+    /// Returns true if requests to GitHub are authenticated with a token.
+    pub fn has_github_token(&self) -> bool {
+        self.inner.authorization.is_some()
+    }
+
+    /// Pulls the data of a downloader to a DataFrame, see `downloader::pull`.
+    ///
+    /// # Arguments
+    ///
+    /// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
+    pub fn pull<D>(&self, downloader: &D) -> Result<DataFrame>
+    where
+        D: Downloader,
+    {
+        downloader::pull_from(self, downloader)
+    }
+
+    /// Downloads the data of a downloader to a specified path, see `downloader::download_to`.
+    ///
+    /// # Arguments
+    ///
+    /// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
+    /// * `path`        -   Destination path. If none, the file will be downloaded to the temporary dictionary.
+    /// * `force`       -   Overwrites exiting files if true. If None or false returns `Error::FileExists` and does not write the file.
+    pub fn download_to<D>(
+        &self,
+        downloader: &D,
+        path: Option<&PathBuf>,
+        force: Option<bool>,
+    ) -> Result<PathBuf>
+    where
+        D: Downloader,
+    {
+        downloader::download_from(self, downloader, path, force)
+    }
+
+    /// Pulls the data of a downloader along with its sources, see `downloader::pull_with_sources`.
+    ///
+    /// # Arguments
+    ///
+    /// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
+    pub fn pull_with_sources<D>(&self, downloader: &D) -> Result<Pulled>
+    where
+        D: Downloader,
+    {
+        downloader::pull_with_sources_from(self, downloader)
+    }
+
+    /// Release assets of the files a downloader spans, see `releases::assets_of`.
+    ///
+    /// # Arguments
+    ///
+    /// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
+    pub fn assets_of<D>(&self, downloader: &D) -> Result<Vec<ReleaseAsset>>
+    where
+        D: Downloader,
+    {
+        releases::assets_of_from(self, downloader)
+    }
+
+    /// Collects a diagnostics report for this client, see `sitrep::sitrep`.
+    pub fn sitrep(&self) -> Sitrep {
+        sitrep::sitrep_from(self)
+    }
+
+    /// Downloads the data of many downloaders concurrently, see `batch::download_all`.
+    ///
+    /// Finished downloaders are reported to the progress hook of the client.
+    pub fn download_all<D>(
+        &self,
+        downloaders: &[D],
+        dir: Option<&PathBuf>,
+        force: Option<bool>,
+        workers: Option<usize>,
+    ) -> Vec<Result<PathBuf>>
+    where
+        D: Downloader + Sync,
+    {
+        batch::download_all_from(self, downloaders, dir, force, workers)
+    }
+
+    /// Pulls the data of many downloaders concurrently, see `batch::pull_all`.
+    ///
+    /// Finished downloaders are reported to the progress hook of the client.
+    pub fn pull_all<D>(&self, downloaders: &[D], workers: Option<usize>) -> Vec<Result<DataFrame>>
+    where
+        D: Downloader + Sync,
+    {
+        batch::pull_all_from(self, downloaders, workers)
+    }
+
+    /// Lists the assets of a nflverse-data release, see `releases::list_assets`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` -   Tag of the release, e.g. `pbp`, `stats_player` or `rosters`.
+    pub fn list_assets(&self, tag: &str) -> Result<Vec<ReleaseAsset>> {
+        releases::list_assets_from(self, tag)
+    }
+
+    /// Pulls the data of a downloader to a DataFrame without blocking, see `downloader::pull_async`.
+    ///
+    /// # Arguments
+    ///
+    /// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
+    #[cfg(feature = "async")]
+    pub async fn pull_async<D>(&self, downloader: &D) -> Result<DataFrame>
+    where
+        D: Downloader + Clone + Send + 'static,
+    {
+        downloader::pull_async_from(self, downloader).await
+    }
+
+    /// Downloads the data of a downloader to a specified path without blocking, see `downloader::download_to_async`.
+    ///
+    /// # Arguments
+    ///
+    /// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
+    /// * `path`        -   Destination path. If none, the file will be downloaded to the temporary dictionary.
+    /// * `force`       -   Overwrites exiting files if true. If None or false returns `Error::FileExists` and does not write the file.
+    #[cfg(feature = "async")]
+    pub async fn download_to_async<D>(
+        &self,
+        downloader: &D,
+        path: Option<&PathBuf>,
+        force: Option<bool>,
+    ) -> Result<PathBuf>
+    where
+        D: Downloader + Clone + Send + 'static,
+    {
+        downloader::download_to_async_from(self, downloader, path, force).await
+    }
+
+    /// URLs of a downloader resolved against the base URL of the client.
+    pub(crate) fn urls<D>(&self, downloader: &D) -> Result<Vec<Url>>
+    where
+        D: Downloader,
+    {
+        let base_url = self.base_url()?;
+
+        downloader
+            .urls()?
+            .iter()
+            .map(|url| source::rebase(url, &base_url))
+            .collect()
+    }
+
+    /// Reports progress of a file transfer to the progress hook, if set.
+    pub(crate) fn report_file(&self, url: &Url, downloaded: u64, total: Option<u64>) {
+        if let Some(progress) = &self.inner.settings.progress {
+            progress.file(&FileProgress {
+                url,
+                downloaded,
+                total,
+            });
+        }
+    }
+
+    /// Reports progress of a batch to the progress hook, if set.
+    pub(crate) fn report_batch(&self, batch: &BatchProgress) {
+        if let Some(progress) = &self.inner.settings.progress {
+            progress.batch(batch);
+        }
+    }
+
+    /// Retry policy of the client.
+    pub(crate) fn retry(&self) -> &RetryPolicy {
+        self.options().retry()
+    }
+
+    /// Blocking HTTP client, built on first use.
+    fn http(&self) -> Result<&blocking::Client> {
+        if let Some(http) = self.inner.http.get() {
+            return Ok(http);
+        }
+
+        let http = self
+            .inner
+            .settings
+            .blocking_client(self.inner.proxy.clone())?;
+        Ok(self.inner.http.get_or_init(|| http))
+    }
+
+    /// Non-blocking HTTP client, built on first use.
+    #[cfg(feature = "async")]
+    fn http_async(&self) -> Result<&reqwest::Client> {
+        if let Some(http) = self.inner.http_async.get() {
+            return Ok(http);
+        }
+
+        let http = self.inner.settings.async_client(self.inner.proxy.clone())?;
+        Ok(self.inner.http_async.get_or_init(|| http))
+    }
+
+    /// Runs an attempt until it succeeds or the retry policy gives up.
+    pub(crate) fn retrying<T, F>(&self, mut attempt: F) -> Result<T>
+    where
+        F: FnMut() -> Result<T>,
+    {
+        let mut attempts = 1;
+        loop {
+            match attempt() {
+                Ok(value) => return Ok(value),
+                Err(e) => match self.retry().delay(attempts, &e) {
+                    Some(delay) => thread::sleep(delay),
+                    None => return Err(e),
+                },
+            }
+            attempts += 1;
+        }
+    }
+
+    /// Adds the GitHub token to the headers of a request to GitHub.
+    ///
+    /// Requests to other hosts, e.g. mirrors, never carry the token.
+    fn authorize(&self, url: &Url, mut headers: HeaderMap) -> HeaderMap {
+        if let Some(authorization) = &self.inner.authorization
+            && is_github(url)
+        {
+            headers.insert(AUTHORIZATION, authorization.clone());
+        }
+
+        headers
+    }
+
+    /// Sends a GET request to a URL.
+    ///
+    /// Returns `Error::Http` with the URL and status if the status is not successful, see `check_status`.
+    pub(crate) fn send(&self, url: &Url, headers: HeaderMap) -> Result<blocking::Response> {
+        let conditional = is_conditional(&headers);
+        let headers = self.authorize(url, headers);
+
+        let response = self.http()?.get(url.clone()).headers(headers).send()?;
+
+        check_status(url, response.status(), response.headers(), conditional)?;
+        Ok(response)
+    }
+
+    /// Sends a GET request to the GitHub API and parses the JSON response.
+    pub(crate) fn get_json<T>(&self, url: &Url) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        self.retrying(|| {
+            let body = self.send(url, create_headers())?.bytes()?;
+
+            Ok(serde_json::from_slice(&body)?)
+        })
+    }
+
+    /// Sends a GET request to a URL without blocking, see `send`.
+    #[cfg(feature = "async")]
+    pub(crate) async fn send_async(
+        &self,
+        url: &Url,
+        headers: HeaderMap,
+    ) -> Result<reqwest::Response> {
+        let conditional = is_conditional(&headers);
+        let headers = self.authorize(url, headers);
+
+        let response = self
+            .http_async()?
+            .get(url.clone())
+            .headers(headers)
+            .send()
+            .await?;
+
+        check_status(url, response.status(), response.headers(), conditional)?;
+        Ok(response)
+    }
+}
+
+/// Returns true if a URL points to GitHub.
+fn is_github(url: &Url) -> bool {
+    url.scheme() == "https"
+        && url
+            .host_str()
+            .is_some_and(|host| host == "github.com" || host.ends_with(".github.com"))
+}
+
+/// Reads a GitHub token from the first set variable of `GITHUB_TOKEN_ENVS`.
+fn github_token_from_env() -> Option<String> {
+    GITHUB_TOKEN_ENVS.iter().find_map(|name| {
+        env::var(name)
+            .ok()
+            .map(|token| token.trim().to_string())
+            .filter(|token| !token.is_empty())
+    })
+}
+
+/// Builder of a `NflreadClient`.
+#[derive(Clone)]
+pub struct NflreadClientBuilder {
+    options: RequestOptions,
+    user_agent: String,
+    github_token: Option<String>,
+    base_url: Option<Url>,
+    api_url: Option<Url>,
+    progress: Option<Arc<dyn Progress>>,
+    proxy: Option<Url>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+}
+
+impl NflreadClientBuilder {
+    /// Sets the retry policy and timeouts, the default options if not set.
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Sets the user agent, `DEFAULT_USER_AGENT` if not set.
+    pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Sets the GitHub token, read from `GITHUB_TOKEN_ENVS` if not set.
+    pub fn with_github_token(mut self, token: impl Into<String>) -> Self {
+        self.github_token = Some(token.into());
+        self
+    }
+
+    /// Sends requests to GitHub anonymously, even if a token is set in the environment.
+    pub fn without_github_token(mut self) -> Self {
+        self.github_token = None;
+        self
+    }
+
+    /// Downloads data from a mirror instead of GitHub, `source::base_url` if not set.
+    ///
+    /// The mirror has to serve releases under the same paths as GitHub, e.g.
+    /// `pbp/play_by_play_2024.csv` below the base URL.
+    pub fn with_base_url(mut self, base_url: Url) -> Self {
+        self.base_url = Some(source::as_base(base_url));
+        self
+    }
+
+    /// Sets the GitHub API release assets are listed with, e.g. of a GitHub Enterprise
+    /// mirror of nflverse-data. `source::DEFAULT_API_URL` if not set.
+    pub fn with_api_url(mut self, api_url: Url) -> Self {
+        self.api_url = Some(source::as_base(api_url));
+        self
+    }
+
+    /// Sets a hook receiving the progress of downloads, see the `progress` module.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nflreadrs::client::NflreadClient;
+    /// use nflreadrs::progress::FileProgress;
+    ///
+    /// let client = NflreadClient::builder()
+    ///     .with_progress(|progress: &FileProgress| {
+    ///         if let Some(total) = progress.total {
+    ///             eprintln!("{}: {}/{} bytes", progress.url, progress.downloaded, total);
+    ///         }
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_progress(mut self, progress: impl Progress + 'static) -> Self {
+        self.progress = Some(Arc::new(progress));
+        self
+    }
+
+    /// Sends all requests through a proxy. The system proxy is used if not set.
+    pub fn with_proxy(mut self, proxy: Url) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept open per host.
+    pub fn with_pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Sets how long idle connections are kept open. Kept open indefinitely if None.
+    pub fn with_pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.pool_idle_timeout = timeout;
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// Returns `Error::Network` if the proxy is invalid and `Error::InvalidToken`
+    /// if the GitHub token cannot be sent in a header.
+    pub fn build(self) -> Result<NflreadClient> {
+        let proxy = match &self.proxy {
+            Some(proxy) => Some(Proxy::all(proxy.clone())?),
+            None => None,
+        };
+        let authorization = match &self.github_token {
+            Some(token) => {
+                let mut value = HeaderValue::from_str(&format!("Bearer {}", token))
+                    .map_err(|_| Error::InvalidToken)?;
+                value.set_sensitive(true);
+                Some(value)
+            }
+            None => None,
+        };
+
+        Ok(NflreadClient {
+            inner: Arc::new(Inner {
+                settings: self,
+                proxy,
+                authorization,
+                http: OnceLock::new(),
+                #[cfg(feature = "async")]
+                http_async: OnceLock::new(),
+            }),
+        })
+    }
+
+    /// Builds the blocking HTTP client.
+    fn blocking_client(&self, proxy: Option<Proxy>) -> Result<blocking::Client> {
+        let mut http = blocking::Client::builder()
+            .user_agent(&self.user_agent)
+            .connect_timeout(self.options.connect_timeout())
+            .timeout(self.options.read_timeout())
+            .pool_idle_timeout(self.pool_idle_timeout);
+        if let Some(proxy) = proxy {
+            http = http.proxy(proxy);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            http = http.pool_max_idle_per_host(max);
+        }
+
+        Ok(http.build()?)
+    }
+
+    /// Builds the non-blocking HTTP client with the same settings.
+    #[cfg(feature = "async")]
+    fn async_client(&self, proxy: Option<Proxy>) -> Result<reqwest::Client> {
+        let mut http = reqwest::Client::builder()
+            .user_agent(&self.user_agent)
+            .pool_idle_timeout(self.pool_idle_timeout);
+        if let Some(timeout) = self.options.connect_timeout() {
+            http = http.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.options.read_timeout() {
+            http = http.read_timeout(timeout);
+        }
+        if let Some(proxy) = proxy {
+            http = http.proxy(proxy);
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            http = http.pool_max_idle_per_host(max);
+        }
+
+        Ok(http.build()?)
+    }
+}
+
+impl Default for NflreadClientBuilder {
+    // Default builder, see `NflreadClient::builder`.
+    fn default() -> Self {
+        Self {
+            options: RequestOptions::default(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            github_token: github_token_from_env(),
+            base_url: None,
+            api_url: None,
+            progress: None,
+            proxy: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+        }
+    }
+}
+
+impl fmt::Debug for NflreadClientBuilder {
+    // Debug output hiding the GitHub token.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NflreadClientBuilder")
+            .field("options", &self.options)
+            .field("user_agent", &self.user_agent)
+            .field("github_token", &self.github_token.as_ref().map(|_| "***"))
+            .field("base_url", &self.base_url)
+            .field("api_url", &self.api_url)
+            .field("progress", &self.progress.is_some())
+            .field("proxy", &self.proxy)
+            .field("pool_max_idle_per_host", &self.pool_max_idle_per_host)
+            .field("pool_idle_timeout", &self.pool_idle_timeout)
+            .finish()
+    }
+}
+
+/// Default client of the free functions, rebuilt when the global request options
+/// or the GitHub token in the environment change.
+static DEFAULT_CLIENT: RwLock<Option<NflreadClient>> = RwLock::new(None);
+
+/// Returns the client used by the free functions of the `downloader` module.
+///
+/// The client is built from the options set with `request::set_options` and shared
+/// between calls, so connections are reused.
+pub fn default_client() -> Result<NflreadClient> {
+    let options = request::options();
+    let github_token = github_token_from_env();
+
+    if let Some(client) = DEFAULT_CLIENT
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        && client.options() == &options
+        && client.inner.settings.github_token == github_token
+    {
+        return Ok(client.clone());
+    }
+
+    let client = NflreadClient::builder().with_options(options).build()?;
+    *DEFAULT_CLIENT.write().unwrap_or_else(|e| e.into_inner()) = Some(client.clone());
+
+    Ok(client)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::PlayByPlay;
+    use crate::test_server::{Response, TestServer};
+    use crate::test_support::UrlDownloader;
+    use std::env;
+    use std::fs;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_download_to_sends_user_agent() {
+        let server = TestServer::start(|_| Response::ok("season\n2024\n"));
+        let client = NflreadClient::builder()
+            .with_user_agent("nflreadrs-test/1.0")
+            .build()
+            .unwrap();
+
+        let url = server.url("/pbp/play_by_play_2024.csv");
+        let path = client.download_to(&UrlDownloader(url), None, None).unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "season\n2024\n");
+        assert_eq!(
+            server.requests()[0].header("User-Agent"),
+            Some("nflreadrs-test/1.0")
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_default_user_agent() {
+        let server = TestServer::start(|_| Response::ok("season\n2024\n"));
+        let path = env::temp_dir().join(format!("nflreadrs-test-{}.csv", uuid::Uuid::new_v4()));
+
+        let url = server.url("/pbp/play_by_play_2024.csv");
+        default_client()
+            .unwrap()
+            .download_to(&UrlDownloader(url), Some(&path), None)
+            .unwrap();
+
+        assert_eq!(
+            server.requests()[0].header("User-Agent"),
+            Some(DEFAULT_USER_AGENT)
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_token_only_sent_to_github() {
+        let client = NflreadClient::builder()
+            .with_github_token("ghp_secret")
+            .build()
+            .unwrap();
+        assert!(client.has_github_token());
+
+        let cases = [
+            // (url, expected to carry the token)
+            (
+                "https://api.github.com/repos/nflverse/nflverse-data/releases",
+                true,
+            ),
+            (
+                "https://github.com/nflverse/nflverse-data/releases/download/pbp/play_by_play_2024.csv",
+                true,
+            ),
+            ("http://github.com/nflverse", false),
+            (
+                "https://objects.githubusercontent.com/release-assets/1",
+                false,
+            ),
+            ("https://github.com.example.com/pbp", false),
+            ("http://127.0.0.1:8080/pbp/play_by_play_2024.csv", false),
+        ];
+
+        for (url, exp) in cases {
+            let headers = client.authorize(&Url::parse(url).unwrap(), HeaderMap::new());
+            assert_eq!(headers.contains_key(AUTHORIZATION), exp, "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_token_is_hidden() {
+        let builder = NflreadClient::builder().with_github_token("ghp_secret");
+        assert!(!format!("{:?}", builder).contains("ghp_secret"));
+
+        let client = builder.build().unwrap();
+        assert!(!format!("{:?}", client).contains("ghp_secret"));
+        assert!(
+            !NflreadClient::builder()
+                .without_github_token()
+                .build()
+                .unwrap()
+                .has_github_token()
+        );
+    }
+
+    #[test]
+    fn test_invalid_token() {
+        let result = NflreadClient::builder()
+            .with_github_token("ghp_\nsecret")
+            .build();
+
+        assert!(matches!(result, Err(Error::InvalidToken)));
+    }
+
+    #[test]
+    fn test_reports_file_progress() {
+        let server = TestServer::start(|_| Response::ok("season\n2024\n"));
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&events);
+        let client = NflreadClient::builder()
+            .with_progress(move |progress: &FileProgress| {
+                recorded
+                    .lock()
+                    .unwrap()
+                    .push((progress.downloaded, progress.total));
+            })
+            .build()
+            .unwrap();
+
+        let url = server.url("/pbp/play_by_play_2024.csv");
+        let path = client.download_to(&UrlDownloader(url), None, None).unwrap();
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.first(), Some(&(0, Some(12))));
+        assert_eq!(events.last(), Some(&(12, Some(12))));
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_downloads_from_mirror() {
+        let server = TestServer::start(|_| Response::ok("season\n2024\n"));
+        let client = NflreadClient::builder()
+            .with_base_url(server.url("/nflverse"))
+            .with_github_token("ghp_secret")
+            .build()
+            .unwrap();
+
+        let path = client
+            .download_to(&PlayByPlay::new(Some(vec![2024])), None, None)
+            .unwrap();
+
+        let request = &server.requests()[0];
+        assert_eq!(request.path, "/nflverse/pbp/play_by_play_2024.csv");
+        assert_eq!(request.header("Authorization"), None);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_requests_go_through_proxy() {
+        let proxy = TestServer::start(|_| Response::ok("season\n2024\n"));
+        let client = NflreadClient::builder()
+            .with_proxy(proxy.url("/"))
+            .build()
+            .unwrap();
+
+        let url = Url::parse("http://nflverse.invalid/pbp/play_by_play_2024.csv").unwrap();
+        let path = client
+            .download_to(&UrlDownloader(url.clone()), None, None)
+            .unwrap();
+
+        assert_eq!(proxy.requests()[0].path, url.as_str());
+
+        fs::remove_file(path).unwrap();
+    }
+}
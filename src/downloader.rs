@@ -1,21 +1,192 @@
 //! Download data with specified configuration.
-use anyhow::Result;
+//!
+//! With the `async` feature enabled, `pull_async` and `download_to_async` provide
+//! non-blocking counterparts of `pull` and `download_to` for use inside async runtimes.
+use crate::cache::{self, CacheEntry};
+use crate::client::{self, NflreadClient};
+use crate::error::{Error, Result};
+use crate::request::{self, RateLimit, RequestOptions};
+use flate2::Compression;
+use flate2::write::GzEncoder;
 use polars::prelude::*;
+use polars_core::utils::try_get_supertype;
+use reqwest::StatusCode;
 use reqwest::blocking;
-use reqwest::header::{ACCEPT, HeaderMap, HeaderValue, USER_AGENT};
+use reqwest::header::{
+    ACCEPT, ETAG, HeaderMap, HeaderName, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH,
+    LAST_MODIFIED,
+};
 use std::env;
-use std::fs::File;
-use std::path::PathBuf;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 use url::Url;
 use uuid::Uuid;
 
+#[cfg(feature = "async")]
+mod nonblocking;
+
+#[cfg(feature = "async")]
+pub use nonblocking::{download_to_async, download_to_async_with, pull_async, pull_async_with};
+
+#[cfg(feature = "async")]
+pub(crate) use nonblocking::{download_to_async_from, pull_async_from};
+
 /// Trait that is implemented to download wanted stats.
 pub trait Downloader {
     /// Returns the URL path for this downloader.
     fn url(&self) -> Result<Url>;
+
+    /// Returns the URL paths of all files this downloader spans.
+    ///
+    /// Defaults to the single URL of `url`. Downloaders covering several files,
+    /// e.g. one per season, override this.
+    fn urls(&self) -> Result<Vec<Url>> {
+        Ok(vec![self.url()?])
+    }
+
+    /// Brings the data of a single file into the shape of the downloader before stacking,
+    /// e.g. when the published schema changed between seasons.
+    ///
+    /// Only called for the files `normalizes` returns true for. Returns None if the data is
+    /// already in shape, the default.
+    fn normalize(&self, _url: &Url, _data: &DataFrame) -> Result<Option<DataFrame>> {
+        Ok(None)
+    }
+
+    /// Returns true if the file of a URL has to be passed through `normalize`, checked before
+    /// anything is read. Defaults to false, such files are downloaded as is without parsing.
+    fn normalizes(&self, _url: &Url) -> bool {
+        false
+    }
+
+    /// Returns the filter applied to the rows of the data, e.g. to keep selected weeks.
+    ///
+    /// Defaults to None, keeping all rows. Downloads of filtered downloaders are read,
+    /// filtered and written instead of copied as is.
+    fn filter(&self) -> Option<RowFilter> {
+        None
+    }
+}
+
+/// Keeps the rows whose integer columns hold one of the given values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowFilter {
+    conditions: Vec<(String, Vec<i64>)>,
+}
+
+impl RowFilter {
+    /// Create a filter keeping the rows of `column` matching any of `values`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nflreadrs::downloader::RowFilter;
+    ///
+    /// let first_quarter_of_season = RowFilter::new("week", [1, 2, 3, 4]);
+    /// ```
+    pub fn new(column: impl Into<String>, values: impl IntoIterator<Item = i64>) -> Self {
+        Self {
+            conditions: vec![(column.into(), values.into_iter().collect())],
+        }
+    }
+
+    /// Additionally requires the rows of `column` to match any of `values`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nflreadrs::downloader::RowFilter;
+    ///
+    /// let openers = RowFilter::new("season", [2023, 2024]).and("week", [1]);
+    /// ```
+    pub fn and(mut self, column: impl Into<String>, values: impl IntoIterator<Item = i64>) -> Self {
+        self.conditions
+            .push((column.into(), values.into_iter().collect()));
+        self
+    }
+
+    /// Applies the filter to a DataFrame, keeping the rows matching all conditions.
+    ///
+    /// Returns `Error::Polars` if a column does not exist or is not numeric.
+    pub fn apply(&self, data: &DataFrame) -> Result<DataFrame> {
+        self.conditions
+            .iter()
+            .try_fold(data.clone(), |data, (column, values)| {
+                let column = data.column(column)?.cast(&DataType::Int64)?;
+                let mask: BooleanChunked = column
+                    .i64()?
+                    .into_iter()
+                    .map(|value| Some(value.is_some_and(|value| values.contains(&value))))
+                    .collect();
+
+                Ok(data.filter(&mask)?)
+            })
+    }
+}
+
+/// Data of a file in the shape of its downloader, see `Downloader::normalize`.
+pub(crate) fn normalized<D>(downloader: &D, url: &Url, data: DataFrame) -> Result<DataFrame>
+where
+    D: Downloader + ?Sized,
+{
+    if !downloader.normalizes(url) {
+        return Ok(data);
+    }
+
+    Ok(downloader.normalize(url, &data)?.unwrap_or(data))
+}
+
+/// Applies the filter of a downloader to its data if it has one, see `Downloader::filter`.
+pub(crate) fn filter_rows(filter: Option<&RowFilter>, data: DataFrame) -> Result<DataFrame> {
+    match filter {
+        Some(filter) => filter.apply(&data),
+        None => Ok(data),
+    }
+}
+
+/// File formats in which nflverse publishes its data.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FileFormat {
+    #[default]
+    Csv,
+    CsvGz,
+    Parquet,
+}
+
+impl FileFormat {
+    /// File extension of the format without the leading dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            FileFormat::Csv => "csv",
+            FileFormat::CsvGz => "csv.gz",
+            FileFormat::Parquet => "parquet",
+        }
+    }
+
+    /// Infers the format from the file extension of a URL. Falls back to CSV if unknown.
+    pub fn from_url(url: &Url) -> Self {
+        FileFormat::from_name(url.path()).unwrap_or_default()
+    }
+
+    /// Infers the format from the extension of a file name, None if unknown.
+    pub fn from_name(name: &str) -> Option<Self> {
+        if name.ends_with(".parquet") {
+            Some(FileFormat::Parquet)
+        } else if name.ends_with(".csv.gz") {
+            Some(FileFormat::CsvGz)
+        } else if name.ends_with(".csv") {
+            Some(FileFormat::Csv)
+        } else {
+            None
+        }
+    }
 }
 
 /// Reads a downloaded CSV file to DataFrame.
+///
+/// Compressed files are decompressed transparently.
 fn from_csv(path: PathBuf, infer_rows: Option<usize>) -> Result<DataFrame> {
     Ok(CsvReadOptions::default()
         .with_has_header(true)
@@ -24,10 +195,167 @@ fn from_csv(path: PathBuf, infer_rows: Option<usize>) -> Result<DataFrame> {
         .finish()?)
 }
 
+/// Reads a downloaded parquet file to DataFrame.
+fn from_parquet(path: &Path) -> Result<DataFrame> {
+    Ok(ParquetReader::new(File::open(path)?).finish()?)
+}
+
+/// Reads a downloaded file of the given format to DataFrame.
+fn from_file(path: &Path, format: FileFormat) -> Result<DataFrame> {
+    match format {
+        FileFormat::Csv | FileFormat::CsvGz => from_csv(path.to_path_buf(), None),
+        FileFormat::Parquet => from_parquet(path),
+    }
+}
+
+/// Writes a DataFrame to a file of the given format, see `write_atomic`.
+fn to_file(data: &mut DataFrame, path: &Path, format: FileFormat) -> Result<()> {
+    write_atomic(path, |file| {
+        match format {
+            FileFormat::Csv => CsvWriter::new(file).finish(data)?,
+            FileFormat::CsvGz => {
+                let mut encoder = GzEncoder::new(file, Compression::default());
+                CsvWriter::new(&mut encoder).finish(data)?;
+                encoder.finish()?;
+            }
+            FileFormat::Parquet => {
+                ParquetWriter::new(file).finish(data)?;
+            }
+        }
+
+        Ok(())
+    })
+}
+
+/// Writes a file atomically.
+///
+/// The content is written to a temporary file next to the destination, which is renamed
+/// to the destination only after writing succeeded. On failure the temporary file is
+/// removed and an existing file at the destination is left untouched.
+pub(crate) fn write_atomic<F>(path: &Path, write: F) -> Result<()>
+where
+    F: FnOnce(&mut File) -> Result<()>,
+{
+    let part = part_path(path);
+
+    let result = File::create(&part)
+        .map_err(Error::from)
+        .and_then(|mut file| {
+            write(&mut file)?;
+            file.sync_all()?;
+            Ok(())
+        })
+        .and_then(|_| Ok(fs::rename(&part, path)?));
+
+    if result.is_err() {
+        let _ = fs::remove_file(&part);
+    }
+
+    result
+}
+
+/// Path of the temporary file a destination is written to before it is renamed.
+pub(crate) fn part_path(path: &Path) -> PathBuf {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!(".{}.{}.part", name, Uuid::new_v4()))
+}
+
+/// Common type of two columns which are to be stacked.
+///
+/// The supertype polars resolves, e.g. Int64 for Int32 and Int64 or Float64 for Int64 and
+/// Float32. Types without a supertype fall back to String.
+fn common_dtype(left: &DataType, right: &DataType) -> DataType {
+    try_get_supertype(left, right).unwrap_or(DataType::String)
+}
+
+/// Vertically stacks DataFrames, aligning their schemas.
+///
+/// Columns missing in a frame, e.g. columns added in later seasons, are filled with nulls.
+/// Columns whose types differ between frames are cast to a common type. Columns without
+/// any values in a frame, e.g. inferred as String from an empty CSV column, do not take
+/// part in finding that type.
+pub(crate) fn stack(frames: Vec<DataFrame>) -> Result<DataFrame> {
+    let mut schema = Schema::default();
+    for frame in &frames {
+        for column in frame.get_columns() {
+            let dtype = match column.null_count() == column.len() {
+                true => &DataType::Null,
+                false => column.dtype(),
+            };
+            let dtype = match schema.get(column.name()) {
+                Some(current) => common_dtype(current, dtype),
+                None => dtype.clone(),
+            };
+            schema.with_column(column.name().clone(), dtype);
+        }
+    }
+
+    // Columns without values in every frame keep the type of their first frame.
+    for frame in &frames {
+        for column in frame.get_columns() {
+            if schema.get(column.name()) == Some(&DataType::Null) {
+                schema.with_column(column.name().clone(), column.dtype().clone());
+            }
+        }
+    }
+
+    let mut stacked: Option<DataFrame> = None;
+    for frame in frames {
+        let height = frame.height();
+        let columns = schema
+            .iter()
+            .map(|(name, dtype)| match frame.column(name) {
+                Ok(column) => column.cast(dtype),
+                Err(_) => Ok(Column::full_null(name.clone(), height, dtype)),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let aligned = DataFrame::new(columns)?;
+
+        match stacked.as_mut() {
+            Some(df) => {
+                df.vstack_mut(&aligned)?;
+            }
+            None => stacked = Some(aligned),
+        }
+    }
+
+    let mut stacked = stacked.unwrap_or_default();
+    stacked.rechunk_mut();
+    Ok(stacked)
+}
+
+/// Where and when a file of pulled data was retrieved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataSource {
+    /// URL the file was read from.
+    pub url: Url,
+    /// Time the file was downloaded or last revalidated with the host.
+    ///
+    /// For files read from the cache this is the time of the cache entry, not of the pull.
+    /// For local files it is the time the file was last modified.
+    pub retrieved_at: SystemTime,
+}
+
+/// Pulled data along with the files it was read from, see `pull_with_sources`.
+#[derive(Debug, Clone)]
+pub struct Pulled {
+    /// The pulled data.
+    pub data: DataFrame,
+    /// One source per file, in the order the files were stacked.
+    pub sources: Vec<DataSource>,
+}
+
 /// Called on a Downloader to pull the data to a DataFrame.
 ///
-/// This fetches the desired data by downloading it into the temporary directory,
-/// loads it into memory and returns it as a polars::DataFrame.
+/// Returns `Error::Http` if the host does not provide a file, e.g. for seasons which are not released yet.
+///
+/// This fetches the desired data by downloading it into the cache directory,
+/// loads it into memory and returns it as a polars::DataFrame. Cached files are
+/// reused until they exceed the maximum age and revalidated with the host afterwards,
+/// see the `cache` module. The file format is
+/// inferred from the URL, see `FileFormat::from_url`.
+/// If the downloader spans several files, e.g. multiple seasons, all of them are
+/// downloaded and stacked into one DataFrame.
 ///
 /// # Arguments
 ///
@@ -36,14 +364,202 @@ pub fn pull<D>(downloader: &D) -> Result<DataFrame>
 where
     D: Downloader,
 {
-    let path_to_file = download_to(downloader, None, None)?;
-    from_csv(path_to_file, None)
+    client::default_client()?.pull(downloader)
+}
+
+/// Called on a Downloader to pull the data to a DataFrame with the given request options.
+///
+/// Behaves like `pull`, but uses the given retry policy and timeouts instead of
+/// the ones set with `request::set_options`.
+///
+/// # Arguments
+///
+/// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
+/// * `options`     -   Retry policy and timeouts of the requests.
+pub fn pull_with<D>(downloader: &D, options: &RequestOptions) -> Result<DataFrame>
+where
+    D: Downloader,
+{
+    NflreadClient::builder()
+        .with_options(options.clone())
+        .build()?
+        .pull(downloader)
+}
+
+/// Called on a Downloader to pull the data along with where and when it was retrieved.
+///
+/// Behaves like `pull`. The sources record the URL and retrieval time of every file,
+/// e.g. to show how stale the data is.
+///
+/// # Arguments
+///
+/// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
+///
+/// # Examples
+///
+/// ```no_run
+/// use nflreadrs::downloader::pull_with_sources;
+/// use nflreadrs::stats::PlayByPlay;
+///
+/// let pulled = pull_with_sources(&PlayByPlay::new(Some(vec![2024]))).unwrap();
+///
+/// for source in &pulled.sources {
+///     println!("{} retrieved {:?} ago", source.url, source.retrieved_at.elapsed().unwrap());
+/// }
+/// ```
+pub fn pull_with_sources<D>(downloader: &D) -> Result<Pulled>
+where
+    D: Downloader,
+{
+    client::default_client()?.pull_with_sources(downloader)
+}
+
+/// Pulls the data of a downloader with a client, see `pull`.
+pub(crate) fn pull_from<D>(client: &NflreadClient, downloader: &D) -> Result<DataFrame>
+where
+    D: Downloader,
+{
+    Ok(pull_with_sources_from(client, downloader)?.data)
+}
+
+/// Pulls the data of a downloader and its sources with a client, see `pull_with_sources`.
+pub(crate) fn pull_with_sources_from<D>(client: &NflreadClient, downloader: &D) -> Result<Pulled>
+where
+    D: Downloader,
+{
+    let (frames, sources): (Vec<_>, Vec<_>) = client
+        .urls(downloader)?
+        .iter()
+        .map(|url| {
+            let (data, source) = read_url(client, url)?;
+            Ok((normalized(downloader, url, data)?, source))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+
+    Ok(Pulled {
+        data: filter_rows(downloader.filter().as_ref(), stack(frames)?)?,
+        sources,
+    })
+}
+
+/// Reads the file behind a URL through the cache.
+///
+/// If caching is disabled, the file is downloaded to the temporary directory and removed after reading.
+/// Local files are read in place.
+fn read_url(client: &NflreadClient, url: &Url) -> Result<(DataFrame, DataSource)> {
+    let format = FileFormat::from_url(url);
+    let source = |retrieved_at| DataSource {
+        url: url.clone(),
+        retrieved_at,
+    };
+
+    if let Some(path) = local_path(url)? {
+        let modified = fs::metadata(&path)?.modified()?;
+        return Ok((from_file(&path, format)?, source(modified)));
+    }
+
+    if !cache::is_enabled() {
+        let path = destination(None, None, format)?;
+        fetch(client, url, &path)?;
+        let retrieved_at = SystemTime::now();
+
+        let data = from_file(&path, format);
+        fs::remove_file(&path)?;
+
+        return Ok((data?, source(retrieved_at)));
+    }
+
+    let dir = cache::cache_dir();
+    let path = fetch_cached(client, url, &dir, cache::max_age())?;
+    let retrieved_at = cache::lookup(&dir, url)?
+        .map(|entry| entry.downloaded)
+        .unwrap_or_else(SystemTime::now);
+
+    Ok((from_file(&path, format)?, source(retrieved_at)))
+}
+
+/// Returns the cached file of a URL, downloading it if needed.
+///
+/// Entries younger than `max_age` are used as is. Older entries are revalidated with a
+/// conditional request using their `ETag` and `Last-Modified` headers and only downloaded
+/// again if the host does not answer with 304 Not Modified.
+fn fetch_cached(
+    client: &NflreadClient,
+    url: &Url,
+    dir: &Path,
+    max_age: Duration,
+) -> Result<PathBuf> {
+    client.retrying(|| {
+        let cached = cache::lookup(dir, url)?;
+
+        if let Some(entry) = &cached
+            && !entry.is_expired(max_age)
+        {
+            return Ok(entry.path.clone());
+        }
+
+        let response = client.send(url, revalidation_headers(cached.as_ref()))?;
+
+        if let Some(entry) = cached
+            && response.status() == StatusCode::NOT_MODIFIED
+        {
+            return keep_entry(dir, url, entry);
+        }
+
+        let etag = header_string(response.headers(), ETAG);
+        let last_modified = header_string(response.headers(), LAST_MODIFIED);
+
+        let path = cache::prepare(dir, url)?;
+        write_body(client, url, response, &path)?;
+        cache::commit(dir, url, etag.as_deref(), last_modified.as_deref())?;
+
+        Ok(path)
+    })
+}
+
+/// Request headers, conditional on the `ETag` and `Last-Modified` headers of a cache entry if given.
+fn revalidation_headers(cached: Option<&CacheEntry>) -> HeaderMap {
+    let mut headers = create_headers();
+
+    if let Some(entry) = cached {
+        if let Some(Ok(etag)) = entry.etag.as_deref().map(HeaderValue::from_str) {
+            headers.insert(IF_NONE_MATCH, etag);
+        }
+        if let Some(Ok(last_modified)) = entry.last_modified.as_deref().map(HeaderValue::from_str) {
+            headers.insert(IF_MODIFIED_SINCE, last_modified);
+        }
+    }
+
+    headers
+}
+
+/// Keeps a cache entry the host answered 304 Not Modified for, resetting its age.
+fn keep_entry(dir: &Path, url: &Url, entry: CacheEntry) -> Result<PathBuf> {
+    cache::commit(
+        dir,
+        url,
+        entry.etag.as_deref(),
+        entry.last_modified.as_deref(),
+    )?;
+
+    Ok(entry.path)
+}
+
+/// Value of a response header as string, None if missing or not valid ASCII.
+fn header_string(headers: &HeaderMap, name: HeaderName) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(String::from)
 }
 
 /// Create headers for the get request on GitHub,
-fn create_headers() -> HeaderMap {
+///
+/// The user agent is set by the client, see `NflreadClient`.
+pub(crate) fn create_headers() -> HeaderMap {
     let mut headers = HeaderMap::new();
-    headers.append(USER_AGENT, HeaderValue::from_static("nflreadrs"));
     headers.append(
         ACCEPT,
         HeaderValue::from_static("application/vnd.github+json"),
@@ -58,12 +574,15 @@ fn create_headers() -> HeaderMap {
 /// Called on a downloader to download data to a specified path.
 ///
 /// If the the specified path is a dict, a UUID based name will be given to the file.
+/// Returns `Error::Http` without writing anything if the host answers with an unsuccessful status.
+/// If the downloader spans several files, e.g. multiple seasons, all of them are
+/// downloaded, stacked and written to the destination as a single file of the same format.
 ///
 /// # Arguments
 ///
 /// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
 /// * `path`        -   Destination path. If none, the file will be downloaded to the temporary dictionary.
-/// * `force`       -   Overwrites exiting files if true. If None or false returns Err and does not write the file.
+/// * `force`       -   Overwrites exiting files if true. If None or false returns `Error::FileExists` and does not write the file.
 pub fn download_to<D>(
     downloader: &D,
     path: Option<&PathBuf>,
@@ -72,18 +591,82 @@ pub fn download_to<D>(
 where
     D: Downloader,
 {
+    client::default_client()?.download_to(downloader, path, force)
+}
+
+/// Called on a downloader to download data to a specified path with the given request options.
+///
+/// Behaves like `download_to`, but uses the given retry policy and timeouts instead of
+/// the ones set with `request::set_options`.
+///
+/// # Arguments
+///
+/// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
+/// * `path`        -   Destination path. If none, the file will be downloaded to the temporary dictionary.
+/// * `force`       -   Overwrites exiting files if true. If None or false returns `Error::FileExists` and does not write the file.
+/// * `options`     -   Retry policy and timeouts of the requests.
+pub fn download_to_with<D>(
+    downloader: &D,
+    path: Option<&PathBuf>,
+    force: Option<bool>,
+    options: &RequestOptions,
+) -> Result<PathBuf>
+where
+    D: Downloader,
+{
+    NflreadClient::builder()
+        .with_options(options.clone())
+        .build()?
+        .download_to(downloader, path, force)
+}
+
+/// Downloads the data of a downloader with a client, see `download_to`.
+pub(crate) fn download_from<D>(
+    client: &NflreadClient,
+    downloader: &D,
+    path: Option<&PathBuf>,
+    force: Option<bool>,
+) -> Result<PathBuf>
+where
+    D: Downloader,
+{
+    let urls = client.urls(downloader)?;
+    let filter = downloader.filter();
+    let format = urls.first().map(FileFormat::from_url).unwrap_or_default();
+    let save_to = destination(path, force, format)?;
+
+    match (urls.as_slice(), &filter) {
+        ([url], None) if !downloader.normalizes(url) => fetch(client, url, &save_to)?,
+        _ => {
+            let frames = urls
+                .iter()
+                .map(|url| normalized(downloader, url, read_url(client, url)?.0))
+                .collect::<Result<Vec<_>>>()?;
+            let mut data = filter_rows(filter.as_ref(), stack(frames)?)?;
+
+            to_file(&mut data, &save_to, format)?;
+        }
+    }
+
+    Ok(save_to)
+}
+
+/// Resolves the destination of a download, see `download_to` for `path` and `force`.
+///
+/// Existing directories are not considered a conflict as the file gets a new name inside them.
+/// Generated file names carry the extension of the given format.
+fn destination(path: Option<&PathBuf>, force: Option<bool>, format: FileFormat) -> Result<PathBuf> {
     let mut save_to: PathBuf;
 
     if let Some(p) = path {
         if p.exists()
+            && !p.is_dir()
             && match force {
                 Some(v) => !v,
                 None => true,
             }
         {
-            anyhow::bail!(
-                "This file already exists. If you want to overwrite it use force = Some(true)"
-            );
+            return Err(Error::FileExists(p.clone()));
         }
         save_to = p.clone();
     } else {
@@ -92,16 +675,807 @@ where
 
     if save_to.is_dir() {
         let id = Uuid::new_v4().to_string();
-        save_to.push(format!("nflreadrs-{}.csv", &id));
+        save_to.push(format!("nflreadrs-{}.{}", &id, format.extension()));
+    }
+
+    Ok(save_to)
+}
+
+/// Downloads the file behind a single URL to the given path.
+///
+/// Nothing is written if the host answers with an unsuccessful status.
+/// Local files are copied.
+fn fetch(client: &NflreadClient, url: &Url, save_to: &Path) -> Result<()> {
+    if let Some(path) = local_path(url)? {
+        return copy_local(&path, save_to);
     }
 
-    let mut file = File::create(&save_to)?;
+    client.retrying(|| {
+        let response = client.send(url, create_headers())?;
 
-    let client = blocking::Client::new();
-    let headers = create_headers();
-    let mut response = client.get(downloader.url()?).headers(headers).send()?;
+        write_body(client, url, response, save_to)
+    })
+}
 
-    response.copy_to(&mut file)?;
+/// Path of the file behind a `file://` URL, None for other URLs.
+///
+/// Returns `Error::MissingFile` if the file does not exist.
+pub(crate) fn local_path(url: &Url) -> Result<Option<PathBuf>> {
+    if url.scheme() != "file" {
+        return Ok(None);
+    }
 
-    Ok(save_to)
+    let path = url.to_file_path().map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("not a file path: {}", url),
+        )
+    })?;
+
+    if !path.is_file() {
+        return Err(Error::MissingFile(path));
+    }
+
+    Ok(Some(path))
+}
+
+/// Copies a local file to the given path, see `write_atomic`.
+fn copy_local(path: &Path, save_to: &Path) -> Result<()> {
+    write_atomic(save_to, |file| {
+        io::copy(&mut File::open(path)?, file)?;
+        Ok(())
+    })
+}
+
+/// Returns true if the headers make a request conditional.
+pub(crate) fn is_conditional(headers: &HeaderMap) -> bool {
+    headers.contains_key(IF_NONE_MATCH) || headers.contains_key(IF_MODIFIED_SINCE)
+}
+
+/// Returns `Error::Http` if the status of a response is not successful.
+///
+/// 304 Not Modified is only accepted as answer to a conditional request.
+/// The error carries the delay of a `Retry-After` header on 429 and 503.
+/// Returns `Error::RateLimited` instead if GitHub reports an exhausted rate limit.
+pub(crate) fn check_status(
+    url: &Url,
+    status: StatusCode,
+    headers: &HeaderMap,
+    conditional: bool,
+) -> Result<()> {
+    if status.is_success() || (conditional && status == StatusCode::NOT_MODIFIED) {
+        return Ok(());
+    }
+
+    if (status == StatusCode::FORBIDDEN || status == StatusCode::TOO_MANY_REQUESTS)
+        && let Some(rate_limit) = RateLimit::from_headers(headers)
+        && rate_limit.is_exhausted()
+    {
+        return Err(Error::RateLimited {
+            url: url.clone(),
+            status,
+            rate_limit: Box::new(rate_limit),
+        });
+    }
+
+    Err(Error::Http {
+        url: url.clone(),
+        status,
+        retry_after: request::retry_after(status, headers),
+    })
+}
+
+/// Writes the body of a response to a file, see `write_atomic`.
+///
+/// The transfer is validated against the `Content-Length` header if present.
+/// Progress is reported to the hook of the client.
+fn write_body(
+    client: &NflreadClient,
+    url: &Url,
+    mut response: blocking::Response,
+    path: &Path,
+) -> Result<()> {
+    let expected = response.content_length();
+
+    write_atomic(path, |file| {
+        client.report_file(url, 0, expected);
+        let written = response.copy_to(&mut ProgressWriter {
+            inner: file,
+            client,
+            url,
+            total: expected,
+            written: 0,
+        })?;
+
+        check_length(expected, written)
+    })
+}
+
+/// Writer reporting the bytes written through it to the progress hook of a client.
+struct ProgressWriter<'a, W> {
+    inner: W,
+    client: &'a NflreadClient,
+    url: &'a Url,
+    total: Option<u64>,
+    written: u64,
+}
+
+impl<W> Write for ProgressWriter<'_, W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.written += n as u64;
+        self.client.report_file(self.url, self.written, self.total);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Returns an error if fewer or more bytes were received than the `Content-Length` announced.
+fn check_length(expected: Option<u64>, written: u64) -> Result<()> {
+    match expected {
+        Some(expected) if expected != written => Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!("received {} of {} bytes", written, expected),
+        )
+        .into()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::RetryPolicy;
+    use crate::test_server::{Response, TestServer};
+    use crate::test_support::{UrlDownloader, test_dir};
+
+    mod file_format_tests {
+        use super::*;
+
+        #[test]
+        fn test_from_url_various_extensions() {
+            let cases = [
+                (
+                    "https://example.com/pbp/play_by_play_2024.csv",
+                    FileFormat::Csv,
+                ),
+                (
+                    "https://example.com/pbp/play_by_play_2024.csv.gz",
+                    FileFormat::CsvGz,
+                ),
+                (
+                    "https://example.com/pbp/play_by_play_2024.parquet",
+                    FileFormat::Parquet,
+                ),
+                ("https://example.com/pbp/play_by_play_2024", FileFormat::Csv),
+            ];
+
+            for (url, exp) in cases {
+                assert_eq!(FileFormat::from_url(&Url::parse(url).unwrap()), exp);
+            }
+        }
+
+        #[test]
+        fn test_write_and_read_round_trip() {
+            for format in [FileFormat::Csv, FileFormat::CsvGz, FileFormat::Parquet] {
+                let mut data = df!("season" => [2023, 2024], "team" => ["KC", "PHI"]).unwrap();
+                let path = destination(None, None, format).unwrap();
+                assert!(path.to_string_lossy().ends_with(format.extension()));
+
+                to_file(&mut data, &path, format).unwrap();
+                let read = from_file(&path, format).unwrap();
+                fs::remove_file(&path).unwrap();
+
+                assert!(read.equals(&data));
+            }
+        }
+    }
+
+    mod row_filter_tests {
+        use super::*;
+
+        #[test]
+        fn test_conditions_are_combined() {
+            let data = df!(
+                "season" => [2023, 2023, 2024, 2024],
+                "week" => [0, 1, 0, 1],
+            )
+            .unwrap();
+            let cases = [
+                // (filter, expected rows)
+                (RowFilter::new("season", [2024]), 2),
+                (RowFilter::new("season", [2024]).and("week", [1]), 1),
+                (RowFilter::new("season", [2023, 2024]).and("week", [0]), 2),
+                (RowFilter::new("season", [2022]).and("week", [0, 1]), 0),
+            ];
+
+            for (filter, exp) in cases {
+                assert_eq!(filter.apply(&data).unwrap().height(), exp, "{:?}", filter);
+            }
+        }
+
+        #[test]
+        fn test_missing_column() {
+            let data = df!("season" => [2024]).unwrap();
+            let filter = RowFilter::new("season", [2024]).and("week", [1]);
+
+            assert!(matches!(filter.apply(&data), Err(Error::Polars(_))));
+        }
+    }
+
+    mod stack_tests {
+        use super::*;
+
+        #[test]
+        fn test_stack_aligns_added_columns() {
+            let old = df!("season" => [2010, 2010], "team" => ["KC", "BUF"]).unwrap();
+            let new = df!("season" => [2025], "team" => ["DET"], "epa" => [0.2]).unwrap();
+
+            let stacked = stack(vec![old, new]).unwrap();
+
+            assert_eq!(stacked.shape(), (3, 3));
+            assert_eq!(stacked.column("epa").unwrap().null_count(), 2);
+        }
+
+        #[test]
+        fn test_stack_casts_to_common_type() {
+            let ints = df!("yards" => [1, 2]).unwrap();
+            let floats = df!("yards" => [1.5]).unwrap();
+            let strings = df!("team" => ["KC"]).unwrap();
+            let ints_as_strings = df!("team" => [1]).unwrap();
+
+            let stacked = stack(vec![ints, floats]).unwrap();
+            assert_eq!(stacked.column("yards").unwrap().dtype(), &DataType::Float64);
+
+            let stacked = stack(vec![strings, ints_as_strings]).unwrap();
+            assert_eq!(stacked.column("team").unwrap().dtype(), &DataType::String);
+        }
+
+        #[test]
+        fn test_stack_keeps_integer_types() {
+            let cases = [
+                // (left, right, expected)
+                (DataType::Int32, DataType::Int64, DataType::Int64),
+                (DataType::UInt32, DataType::Int64, DataType::Int64),
+                (DataType::Int32, DataType::Int32, DataType::Int32),
+                (DataType::Int64, DataType::Float32, DataType::Float64),
+            ];
+
+            for (left, right, exp) in cases {
+                let old = DataFrame::new(vec![
+                    Column::new("season".into(), [2010i64]).cast(&left).unwrap(),
+                ])
+                .unwrap();
+                let new = DataFrame::new(vec![
+                    Column::new("season".into(), [2025i64])
+                        .cast(&right)
+                        .unwrap(),
+                ])
+                .unwrap();
+
+                let stacked = stack(vec![old, new]).unwrap();
+                assert_eq!(stacked.column("season").unwrap().dtype(), &exp);
+            }
+        }
+
+        #[test]
+        fn test_stack_ignores_type_of_null_columns() {
+            let empty = df!(
+                "season" => [2010],
+                "epa" => [None::<&str>],
+                "note" => [None::<&str>],
+            )
+            .unwrap();
+            let filled = df!(
+                "season" => [2025],
+                "epa" => [Some(1i64)],
+                "note" => [None::<&str>],
+            )
+            .unwrap();
+
+            let stacked = stack(vec![empty, filled]).unwrap();
+
+            assert_eq!(stacked.column("epa").unwrap().dtype(), &DataType::Int64);
+            assert_eq!(stacked.column("epa").unwrap().null_count(), 1);
+            assert_eq!(stacked.column("note").unwrap().dtype(), &DataType::String);
+        }
+
+        #[test]
+        fn test_stack_no_frames() {
+            let stacked = stack(vec![]).unwrap();
+            assert!(stacked.is_empty());
+        }
+    }
+
+    /// Request options retrying quickly to keep tests fast.
+    fn fast_retries() -> RequestOptions {
+        RequestOptions::new().with_retry(
+            RetryPolicy::new().with_backoff(Duration::from_millis(1), Duration::from_millis(10)),
+        )
+    }
+
+    /// Client using `fast_retries`.
+    fn client() -> NflreadClient {
+        NflreadClient::builder()
+            .with_options(fast_retries())
+            .build()
+            .unwrap()
+    }
+
+    mod status_tests {
+        use super::*;
+
+        #[test]
+        fn test_download_to_not_found() {
+            let server = TestServer::start(|_| Response::status(404));
+            let url = server.url("/pbp/play_by_play_2030.csv");
+            let path = env::temp_dir().join(format!("nflreadrs-test-{}.csv", Uuid::new_v4()));
+
+            let result = download_to(&UrlDownloader(url.clone()), Some(&path), None);
+
+            assert!(matches!(
+                result,
+                Err(Error::Http { url: u, status, .. }) if u == url && status == StatusCode::NOT_FOUND
+            ));
+            assert!(!path.exists());
+        }
+
+        #[test]
+        fn test_fetch_cached_not_found() {
+            let server = TestServer::start(|_| Response::status(404));
+            let dir = test_dir().join("cache");
+            let url = server.url("/pbp/play_by_play_2030.csv");
+
+            let result = fetch_cached(&client(), &url, &dir, Duration::ZERO);
+
+            assert!(
+                matches!(result, Err(Error::Http { status, .. }) if status == StatusCode::NOT_FOUND)
+            );
+            assert!(cache::lookup(&dir, &url).unwrap().is_none());
+            assert!(!dir.exists());
+        }
+
+        #[test]
+        fn test_unconditional_not_modified() {
+            let server = TestServer::start(|_| Response::status(304));
+            let url = server.url("/pbp/play_by_play_2024.csv");
+
+            let result = client().send(&url, create_headers());
+
+            assert!(
+                matches!(result, Err(Error::Http { status, .. }) if status == StatusCode::NOT_MODIFIED)
+            );
+        }
+    }
+
+    mod retry_tests {
+        use super::*;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[test]
+        fn test_retries_transient_status() {
+            let calls = AtomicUsize::new(0);
+            let server = TestServer::start(move |_| match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => Response::status(503),
+                1 => Response::status(502),
+                _ => Response::ok("season\n2024\n"),
+            });
+            let path = destination(None, None, FileFormat::Csv).unwrap();
+
+            let url = server.url("/pbp/play_by_play_2024.csv");
+            fetch(&client(), &url, &path).unwrap();
+
+            assert_eq!(server.requests().len(), 3);
+            assert_eq!(fs::read_to_string(&path).unwrap(), "season\n2024\n");
+
+            fs::remove_file(path).unwrap();
+        }
+
+        #[test]
+        fn test_gives_up_after_max_attempts() {
+            let server = TestServer::start(|_| Response::status(500));
+            let path = destination(None, None, FileFormat::Csv).unwrap();
+
+            let url = server.url("/pbp/play_by_play_2024.csv");
+            let result = fetch(&client(), &url, &path);
+
+            assert!(
+                matches!(result, Err(Error::Http { status, .. }) if status == StatusCode::INTERNAL_SERVER_ERROR)
+            );
+            assert_eq!(server.requests().len(), 3);
+            assert!(!path.exists());
+        }
+
+        #[test]
+        fn test_not_found_is_not_retried() {
+            let server = TestServer::start(|_| Response::status(404));
+            let path = destination(None, None, FileFormat::Csv).unwrap();
+
+            let url = server.url("/pbp/play_by_play_2030.csv");
+            assert!(fetch(&client(), &url, &path).is_err());
+            assert_eq!(server.requests().len(), 1);
+        }
+
+        #[test]
+        fn test_rate_limited() {
+            let server = TestServer::start(|_| {
+                Response::status(403)
+                    .with_header("x-ratelimit-limit", "60")
+                    .with_header("x-ratelimit-remaining", "0")
+                    .with_header("x-ratelimit-reset", "4102444800")
+            });
+            let path = destination(None, None, FileFormat::Csv).unwrap();
+
+            let url = server.url("/pbp/play_by_play_2024.csv");
+            let result = fetch(&client(), &url, &path);
+
+            assert!(matches!(
+                result,
+                Err(Error::RateLimited { status, rate_limit, .. })
+                    if status == StatusCode::FORBIDDEN && rate_limit.limit == Some(60)
+            ));
+            assert_eq!(server.requests().len(), 1);
+        }
+
+        #[test]
+        fn test_forbidden_without_rate_limit() {
+            let server = TestServer::start(|_| {
+                Response::status(403).with_header("x-ratelimit-remaining", "59")
+            });
+            let path = destination(None, None, FileFormat::Csv).unwrap();
+
+            let url = server.url("/pbp/play_by_play_2024.csv");
+            let result = fetch(&client(), &url, &path);
+
+            assert!(
+                matches!(result, Err(Error::Http { status, .. }) if status == StatusCode::FORBIDDEN)
+            );
+        }
+
+        #[test]
+        fn test_retry_after_is_respected() {
+            let calls = AtomicUsize::new(0);
+            let server = TestServer::start(move |_| match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => Response::status(429).with_header("Retry-After", "1"),
+                _ => Response::ok("season\n2024\n"),
+            });
+            let dir = test_dir();
+
+            let url = server.url("/pbp/play_by_play_2024.csv");
+            let start = std::time::Instant::now();
+            let client = NflreadClient::new().unwrap();
+            fetch_cached(&client, &url, &dir, Duration::ZERO).unwrap();
+
+            assert!(start.elapsed() >= Duration::from_secs(1));
+            assert_eq!(server.requests().len(), 2);
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_truncated_transfer_is_retried() {
+            let calls = AtomicUsize::new(0);
+            let server = TestServer::start(move |_| match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => Response::ok("season\n20").with_header("Content-Length", "100"),
+                _ => Response::ok("season\n2024\n"),
+            });
+            let path = destination(None, None, FileFormat::Csv).unwrap();
+
+            let url = server.url("/pbp/play_by_play_2024.csv");
+            fetch(&client(), &url, &path).unwrap();
+
+            assert_eq!(fs::read_to_string(&path).unwrap(), "season\n2024\n");
+
+            fs::remove_file(path).unwrap();
+        }
+    }
+
+    mod write_atomic_tests {
+        use super::*;
+
+        #[test]
+        fn test_failed_download_keeps_existing_file() {
+            let server = TestServer::start(|_| {
+                Response::ok("season\n20").with_header("Content-Length", "100")
+            });
+            let dir = test_dir();
+            let path = dir.join("play_by_play_2024.csv");
+            fs::write(&path, "season\n2024\n").unwrap();
+
+            let url = server.url("/pbp/play_by_play_2024.csv");
+            let result = download_to_with(
+                &UrlDownloader(url),
+                Some(&path),
+                Some(true),
+                &fast_retries(),
+            );
+
+            assert!(result.is_err());
+            assert_eq!(fs::read_to_string(&path).unwrap(), "season\n2024\n");
+            assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_successful_download_replaces_file() {
+            let server = TestServer::start(|_| Response::ok("season\n2025\n"));
+            let dir = test_dir();
+            let path = dir.join("play_by_play_2025.csv");
+            fs::write(&path, "season\n2024\n").unwrap();
+
+            let url = server.url("/pbp/play_by_play_2025.csv");
+            download_to(&UrlDownloader(url), Some(&path), Some(true)).unwrap();
+
+            assert_eq!(fs::read_to_string(&path).unwrap(), "season\n2025\n");
+            assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+
+    mod destination_tests {
+        use super::*;
+
+        #[test]
+        fn test_existing_file_without_force() {
+            let path = destination(None, None, FileFormat::Csv).unwrap();
+            File::create(&path).unwrap();
+
+            for force in [None, Some(false)] {
+                let result = destination(Some(&path), force, FileFormat::Csv);
+                assert!(matches!(result, Err(Error::FileExists(p)) if p == path));
+            }
+            assert_eq!(
+                destination(Some(&path), Some(true), FileFormat::Csv).unwrap(),
+                path
+            );
+
+            fs::remove_file(path).unwrap();
+        }
+
+        #[test]
+        fn test_existing_dir_without_force() {
+            let dir = test_dir();
+
+            let path = destination(Some(&dir), None, FileFormat::Parquet).unwrap();
+            assert_eq!(path.parent(), Some(dir.as_path()));
+            assert!(path.to_string_lossy().ends_with(".parquet"));
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+
+    mod fetch_cached_tests {
+        use super::*;
+
+        #[test]
+        fn test_fresh_entry_is_reused() {
+            let server = TestServer::start(|_| Response::ok("season\n2024\n"));
+            let dir = test_dir();
+            let url = server.url("/pbp/play_by_play_2024.csv");
+
+            let first = fetch_cached(&client(), &url, &dir, Duration::from_secs(60)).unwrap();
+            let second = fetch_cached(&client(), &url, &dir, Duration::from_secs(60)).unwrap();
+
+            assert_eq!(first, second);
+            assert_eq!(server.requests().len(), 1);
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_expired_entry_not_modified() {
+            let server = TestServer::start(|request| match request.header("If-None-Match") {
+                Some("\"v1\"") => Response::status(304),
+                _ => Response::ok("season\n2024\n")
+                    .with_header("ETag", "\"v1\"")
+                    .with_header("Last-Modified", "Wed, 01 Jan 2025 00:00:00 GMT"),
+            });
+            let dir = test_dir();
+            let url = server.url("/pbp/play_by_play_2024.csv");
+
+            fetch_cached(&client(), &url, &dir, Duration::ZERO).unwrap();
+            let path = fetch_cached(&client(), &url, &dir, Duration::ZERO).unwrap();
+
+            let requests = server.requests();
+            assert_eq!(requests.len(), 2);
+            assert_eq!(requests[0].path, "/pbp/play_by_play_2024.csv");
+            assert_eq!(requests[0].header("If-None-Match"), None);
+            assert_eq!(requests[1].header("If-None-Match"), Some("\"v1\""));
+            assert_eq!(
+                requests[1].header("If-Modified-Since"),
+                Some("Wed, 01 Jan 2025 00:00:00 GMT")
+            );
+            assert_eq!(fs::read_to_string(path).unwrap(), "season\n2024\n");
+
+            let entry = cache::lookup(&dir, &url).unwrap().unwrap();
+            assert_eq!(entry.etag.as_deref(), Some("\"v1\""));
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_expired_entry_modified() {
+            let server = TestServer::start(|request| match request.header("If-None-Match") {
+                Some("\"v1\"") => Response::ok("season\n2025\n").with_header("ETag", "\"v2\""),
+                _ => Response::ok("season\n2024\n").with_header("ETag", "\"v1\""),
+            });
+            let dir = test_dir();
+            let url = server.url("/pbp/play_by_play_2024.csv");
+
+            fetch_cached(&client(), &url, &dir, Duration::ZERO).unwrap();
+            let path = fetch_cached(&client(), &url, &dir, Duration::ZERO).unwrap();
+
+            assert_eq!(fs::read_to_string(path).unwrap(), "season\n2025\n");
+            let entry = cache::lookup(&dir, &url).unwrap().unwrap();
+            assert_eq!(entry.etag.as_deref(), Some("\"v2\""));
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+
+    mod local_source_tests {
+        use super::*;
+        use crate::source;
+        use crate::stats::{DepthCharts, PlayByPlay, WeeklyRosters};
+
+        /// Creates a directory laid out like the releases with the 2024 play by play data.
+        fn release_dir() -> PathBuf {
+            let dir = test_dir();
+            fs::create_dir_all(dir.join("pbp")).unwrap();
+            fs::write(
+                dir.join("pbp/play_by_play_2024.csv"),
+                "season,play_id\n2024,1\n2024,2\n",
+            )
+            .unwrap();
+            dir
+        }
+
+        /// Client reading from a local directory.
+        fn local_client(dir: &Path) -> NflreadClient {
+            NflreadClient::builder()
+                .with_base_url(source::dir_url(dir).unwrap())
+                .build()
+                .unwrap()
+        }
+
+        #[test]
+        fn test_pull_from_local_dir() {
+            let dir = release_dir();
+
+            let data = local_client(&dir)
+                .pull(&PlayByPlay::new(Some(vec![2024])))
+                .unwrap();
+
+            assert_eq!(data.shape(), (2, 2));
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_pull_with_sources_from_local_dir() {
+            let dir = release_dir();
+            let path = dir.join("pbp/play_by_play_2024.csv");
+
+            let pulled = local_client(&dir)
+                .pull_with_sources(&PlayByPlay::new(Some(vec![2024])))
+                .unwrap();
+
+            assert_eq!(pulled.data.shape(), (2, 2));
+            assert_eq!(
+                pulled.sources,
+                vec![DataSource {
+                    url: Url::from_file_path(&path).unwrap(),
+                    retrieved_at: fs::metadata(&path).unwrap().modified().unwrap(),
+                }]
+            );
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_download_from_local_dir() {
+            let dir = release_dir();
+            let path = dir.join("copy.csv");
+
+            local_client(&dir)
+                .download_to(&PlayByPlay::new(Some(vec![2024])), Some(&path), None)
+                .unwrap();
+
+            assert_eq!(
+                fs::read_to_string(path).unwrap(),
+                "season,play_id\n2024,1\n2024,2\n"
+            );
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_filtered_download_is_rewritten() {
+            let dir = release_dir();
+            fs::create_dir_all(dir.join("weekly_rosters")).unwrap();
+            fs::write(
+                dir.join("weekly_rosters/roster_weekly_2024.csv"),
+                "week,status\n1,ACT\n2,RES\n3,ACT\n",
+            )
+            .unwrap();
+            let path = dir.join("weeks.csv");
+            let weekly_rosters = WeeklyRosters::new(Some(vec![2024])).with_weeks(vec![2, 3]);
+
+            let client = local_client(&dir);
+            client
+                .download_to(&weekly_rosters, Some(&path), None)
+                .unwrap();
+
+            assert_eq!(
+                fs::read_to_string(path).unwrap(),
+                "week,status\n2,RES\n3,ACT\n"
+            );
+            assert_eq!(client.pull(&weekly_rosters).unwrap().shape(), (2, 2));
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_single_file_download_is_normalized() {
+            let dir = release_dir();
+            fs::create_dir_all(dir.join("depth_charts")).unwrap();
+            fs::write(
+                dir.join("depth_charts/depth_charts_2025.csv"),
+                "dt,team,player_name,gsis_id,pos_grp,pos_abb,pos_rank\n\
+                 2025-09-01,KC,Patrick Mahomes,00-0033873,Offense,QB,1\n",
+            )
+            .unwrap();
+            let path = dir.join("depth_charts.csv");
+
+            local_client(&dir)
+                .download_to(&DepthCharts::new(Some(vec![2025])), Some(&path), None)
+                .unwrap();
+
+            let data = from_file(&path, FileFormat::Csv).unwrap();
+            assert_eq!(
+                data.column("season").unwrap().i64().unwrap().get(0),
+                Some(2025)
+            );
+            assert!(data.column("pos_rank").is_err());
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_single_file_download_is_not_parsed() {
+            let dir = release_dir();
+            fs::write(dir.join("pbp/play_by_play_2023.parquet"), "not parquet").unwrap();
+            let path = dir.join("play_by_play.parquet");
+            let play_by_play = PlayByPlay::new(Some(vec![2023])).with_format(FileFormat::Parquet);
+
+            local_client(&dir)
+                .download_to(&play_by_play, Some(&path), None)
+                .unwrap();
+
+            assert_eq!(fs::read_to_string(path).unwrap(), "not parquet");
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_missing_local_file() {
+            let dir = release_dir();
+
+            let result = local_client(&dir).pull(&PlayByPlay::new(Some(vec![2023])));
+
+            assert!(
+                matches!(result, Err(Error::MissingFile(path)) if path == dir.join("pbp/play_by_play_2023.csv"))
+            );
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
 }
@@ -0,0 +1,468 @@
+//! Non-blocking counterparts of `pull` and `download_to` for use inside async runtimes.
+//!
+//! Requires the `async` feature. URLs, caching and file handling are shared with the
+//! blocking functions, only the requests are sent with the non-blocking reqwest client.
+//! Reading, normalizing, filtering, stacking and writing DataFrames as well as the cache
+//! bookkeeping run on tokio's blocking thread pool. Downloaders are cloned to normalize
+//! and filter their data there, so they need to be `Clone`, `Send` and `'static`.
+use super::{
+    Downloader, FileFormat, check_length, copy_local, destination, filter_rows, from_file,
+    header_string, keep_entry, local_path, normalized, part_path, revalidation_headers, stack,
+    to_file,
+};
+use crate::cache;
+use crate::client::{self, NflreadClient};
+use crate::error::{Error, Result};
+use crate::request::RequestOptions;
+use polars::prelude::DataFrame;
+use reqwest::header::{ETAG, LAST_MODIFIED};
+use reqwest::{Response, StatusCode};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::fs::{self, File};
+use tokio::io::AsyncWriteExt;
+use url::Url;
+
+/// Called on a Downloader to pull the data to a DataFrame without blocking.
+///
+/// Behaves like `pull`, see there for caching and multi-file downloaders.
+///
+/// # Arguments
+///
+/// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
+pub async fn pull_async<D>(downloader: &D) -> Result<DataFrame>
+where
+    D: Downloader + Clone + Send + 'static,
+{
+    client::default_client()?.pull_async(downloader).await
+}
+
+/// Called on a Downloader to pull the data to a DataFrame without blocking, with the given request options.
+///
+/// Behaves like `pull_with`.
+///
+/// # Arguments
+///
+/// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
+/// * `options`     -   Retry policy and timeouts of the requests.
+pub async fn pull_async_with<D>(downloader: &D, options: &RequestOptions) -> Result<DataFrame>
+where
+    D: Downloader + Clone + Send + 'static,
+{
+    NflreadClient::builder()
+        .with_options(options.clone())
+        .build()?
+        .pull_async(downloader)
+        .await
+}
+
+/// Pulls the data of a downloader with a client without blocking, see `pull_async`.
+pub(crate) async fn pull_async_from<D>(client: &NflreadClient, downloader: &D) -> Result<DataFrame>
+where
+    D: Downloader + Clone + Send + 'static,
+{
+    let files = read_urls(client, client.urls(downloader)?).await?;
+    let downloader = downloader.clone();
+
+    blocking(move || shape(&downloader, files)).await
+}
+
+/// Reads the files behind URLs, see `read_url`.
+async fn read_urls(client: &NflreadClient, urls: Vec<Url>) -> Result<Vec<(Url, DataFrame)>> {
+    let mut files = Vec::new();
+    for url in urls {
+        let data = read_url(client, &url).await?;
+        files.push((url, data));
+    }
+
+    Ok(files)
+}
+
+/// Normalizes, stacks and filters the data of the files of a downloader.
+fn shape<D>(downloader: &D, files: Vec<(Url, DataFrame)>) -> Result<DataFrame>
+where
+    D: Downloader,
+{
+    let frames = files
+        .into_iter()
+        .map(|(url, data)| normalized(downloader, &url, data))
+        .collect::<Result<Vec<_>>>()?;
+
+    filter_rows(downloader.filter().as_ref(), stack(frames)?)
+}
+
+/// Called on a downloader to download data to a specified path without blocking.
+///
+/// Behaves like `download_to`, see there for the handling of `path` and `force`.
+///
+/// # Arguments
+///
+/// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
+/// * `path`        -   Destination path. If none, the file will be downloaded to the temporary dictionary.
+/// * `force`       -   Overwrites exiting files if true. If None or false returns `Error::FileExists` and does not write the file.
+pub async fn download_to_async<D>(
+    downloader: &D,
+    path: Option<&PathBuf>,
+    force: Option<bool>,
+) -> Result<PathBuf>
+where
+    D: Downloader + Clone + Send + 'static,
+{
+    client::default_client()?
+        .download_to_async(downloader, path, force)
+        .await
+}
+
+/// Called on a downloader to download data to a specified path without blocking, with the given request options.
+///
+/// Behaves like `download_to_with`.
+///
+/// # Arguments
+///
+/// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
+/// * `path`        -   Destination path. If none, the file will be downloaded to the temporary dictionary.
+/// * `force`       -   Overwrites exiting files if true. If None or false returns `Error::FileExists` and does not write the file.
+/// * `options`     -   Retry policy and timeouts of the requests.
+pub async fn download_to_async_with<D>(
+    downloader: &D,
+    path: Option<&PathBuf>,
+    force: Option<bool>,
+    options: &RequestOptions,
+) -> Result<PathBuf>
+where
+    D: Downloader + Clone + Send + 'static,
+{
+    NflreadClient::builder()
+        .with_options(options.clone())
+        .build()?
+        .download_to_async(downloader, path, force)
+        .await
+}
+
+/// Downloads the data of a downloader with a client without blocking, see `download_to_async`.
+pub(crate) async fn download_to_async_from<D>(
+    client: &NflreadClient,
+    downloader: &D,
+    path: Option<&PathBuf>,
+    force: Option<bool>,
+) -> Result<PathBuf>
+where
+    D: Downloader + Clone + Send + 'static,
+{
+    let urls = client.urls(downloader)?;
+    let filter = downloader.filter();
+    let format = urls.first().map(FileFormat::from_url).unwrap_or_default();
+    let save_to = destination(path, force, format)?;
+
+    let path = save_to.clone();
+    let downloader = downloader.clone();
+
+    match (urls.as_slice(), filter) {
+        ([url], None) if !downloader.normalizes(url) => fetch(client, url, &save_to).await?,
+        _ => {
+            let files = read_urls(client, urls).await?;
+
+            blocking(move || to_file(&mut shape(&downloader, files)?, &path, format)).await?;
+        }
+    }
+
+    Ok(save_to)
+}
+
+/// Runs blocking work, e.g. parsing a file, on tokio's blocking thread pool.
+async fn blocking<T, F>(work: F) -> Result<T>
+where
+    F: FnOnce() -> Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(work)
+        .await
+        .map_err(io::Error::other)?
+}
+
+/// Reads the file behind a URL through the cache, see `read_url` of the blocking functions.
+async fn read_url(client: &NflreadClient, url: &Url) -> Result<DataFrame> {
+    let format = FileFormat::from_url(url);
+
+    if let Some(path) = local_path(url)? {
+        return blocking(move || from_file(&path, format)).await;
+    }
+
+    if !cache::is_enabled() {
+        let path = destination(None, None, format)?;
+        fetch(client, url, &path).await?;
+
+        return blocking(move || {
+            let data = from_file(&path, format);
+            std::fs::remove_file(&path)?;
+            data
+        })
+        .await;
+    }
+
+    let path = fetch_cached(client, url, &cache::cache_dir(), cache::max_age()).await?;
+
+    blocking(move || from_file(&path, format)).await
+}
+
+/// Returns the cached file of a URL, downloading it if needed.
+///
+/// Expired entries are revalidated like in the blocking `fetch_cached`.
+async fn fetch_cached(
+    client: &NflreadClient,
+    url: &Url,
+    dir: &Path,
+    max_age: Duration,
+) -> Result<PathBuf> {
+    let mut attempts = 1;
+    loop {
+        match try_fetch_cached(client, url, dir, max_age).await {
+            Ok(path) => return Ok(path),
+            Err(e) => backoff(client, attempts, e).await?,
+        }
+        attempts += 1;
+    }
+}
+
+/// Single attempt of `fetch_cached`.
+async fn try_fetch_cached(
+    client: &NflreadClient,
+    url: &Url,
+    dir: &Path,
+    max_age: Duration,
+) -> Result<PathBuf> {
+    let cached = in_cache(dir, url, cache::lookup).await?;
+
+    if let Some(entry) = &cached
+        && !entry.is_expired(max_age)
+    {
+        return Ok(entry.path.clone());
+    }
+
+    let response = client
+        .send_async(url, revalidation_headers(cached.as_ref()))
+        .await?;
+
+    if let Some(entry) = cached
+        && response.status() == StatusCode::NOT_MODIFIED
+    {
+        return in_cache(dir, url, |dir, url| keep_entry(dir, url, entry)).await;
+    }
+
+    let etag = header_string(response.headers(), ETAG);
+    let last_modified = header_string(response.headers(), LAST_MODIFIED);
+
+    let path = in_cache(dir, url, cache::prepare).await?;
+    write_body(client, url, response, &path).await?;
+    in_cache(dir, url, move |dir, url| {
+        cache::commit(dir, url, etag.as_deref(), last_modified.as_deref())
+    })
+    .await?;
+
+    Ok(path)
+}
+
+/// Runs work on the cache directory for a URL on tokio's blocking thread pool.
+async fn in_cache<T, F>(dir: &Path, url: &Url, work: F) -> Result<T>
+where
+    F: FnOnce(&Path, &Url) -> Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    let (dir, url) = (dir.to_path_buf(), url.clone());
+
+    blocking(move || work(&dir, &url)).await
+}
+
+/// Downloads the file behind a single URL to the given path.
+///
+/// Nothing is written if the host answers with an unsuccessful status.
+async fn fetch(client: &NflreadClient, url: &Url, save_to: &Path) -> Result<()> {
+    if let Some(path) = local_path(url)? {
+        let save_to = save_to.to_path_buf();
+        return blocking(move || copy_local(&path, &save_to)).await;
+    }
+
+    let mut attempts = 1;
+    loop {
+        let result = match client.send_async(url, revalidation_headers(None)).await {
+            Ok(response) => write_body(client, url, response, save_to).await,
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(()) => return Ok(()),
+            Err(e) => backoff(client, attempts, e).await?,
+        }
+        attempts += 1;
+    }
+}
+
+/// Waits before the next attempt after a failed one, returns the error if the retry policy gives up.
+async fn backoff(client: &NflreadClient, attempt: u32, error: Error) -> Result<()> {
+    match client.retry().delay(attempt, &error) {
+        Some(delay) => {
+            tokio::time::sleep(delay).await;
+            Ok(())
+        }
+        None => Err(error),
+    }
+}
+
+/// Streams the body of a response to a file.
+///
+/// Like the blocking `write_body` the file is written atomically, validated
+/// against the `Content-Length` header if present and progress is reported.
+async fn write_body(
+    client: &NflreadClient,
+    url: &Url,
+    mut response: Response,
+    path: &Path,
+) -> Result<()> {
+    let expected = response.content_length();
+    let part = part_path(path);
+
+    let result = async {
+        let mut file = File::create(&part).await?;
+        let mut written = 0;
+        client.report_file(url, 0, expected);
+
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+            client.report_file(url, written, expected);
+        }
+
+        check_length(expected, written)?;
+        file.sync_all().await?;
+        fs::rename(&part, path).await?;
+        Ok(())
+    }
+    .await;
+
+    if result.is_err() {
+        let _ = fs::remove_file(&part).await;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::request::RetryPolicy;
+    use crate::test_server::{Response, TestServer};
+    use crate::test_support::{UrlDownloader, test_dir};
+
+    #[tokio::test]
+    async fn test_pull_async_from_local_dir() {
+        let dir = test_dir();
+        std::fs::create_dir_all(dir.join("rosters")).unwrap();
+        std::fs::write(dir.join("rosters/roster_2024.csv"), "season\n2024\n").unwrap();
+        let client = NflreadClient::builder()
+            .with_base_url(crate::source::dir_url(&dir).unwrap())
+            .build()
+            .unwrap();
+
+        let data = client
+            .pull_async(&crate::stats::Rosters::new(Some(vec![2024])))
+            .await
+            .unwrap();
+
+        assert_eq!(data.shape(), (1, 1));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_to_async() {
+        let server = TestServer::start(|_| Response::ok("season\n2024\n"));
+        let dir = test_dir();
+        let path = dir.join("play_by_play_2024.csv");
+
+        let url = server.url("/pbp/play_by_play_2024.csv");
+        let saved = download_to_async(&UrlDownloader(url), Some(&path), None)
+            .await
+            .unwrap();
+
+        assert_eq!(saved, path);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "season\n2024\n");
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_to_async_not_found() {
+        let server = TestServer::start(|_| Response::status(404));
+        let dir = test_dir();
+        let path = dir.join("play_by_play_2030.csv");
+
+        let url = server.url("/pbp/play_by_play_2030.csv");
+        let result = download_to_async(&UrlDownloader(url), Some(&path), None).await;
+        assert_eq!(server.requests().len(), 1);
+
+        assert!(
+            matches!(result, Err(Error::Http { status, .. }) if status == StatusCode::NOT_FOUND)
+        );
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_download_to_async_retries() {
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+        let server = TestServer::start(move |_| {
+            match calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) {
+                0 => Response::status(503),
+                _ => Response::ok("season\n2024\n"),
+            }
+        });
+        let dir = test_dir();
+        let path = dir.join("play_by_play_2024.csv");
+        let options = RequestOptions::new().with_retry(
+            RetryPolicy::new().with_backoff(Duration::from_millis(1), Duration::from_millis(10)),
+        );
+
+        let url = server.url("/pbp/play_by_play_2024.csv");
+        download_to_async_with(&UrlDownloader(url), Some(&path), None, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(server.requests().len(), 2);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "season\n2024\n");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fetch_cached_revalidates() {
+        let server = TestServer::start(|request| match request.header("If-None-Match") {
+            Some("\"v1\"") => Response::status(304),
+            _ => Response::ok("season\n2024\n").with_header("ETag", "\"v1\""),
+        });
+        let dir = test_dir();
+        let url = server.url("/pbp/play_by_play_2024.csv");
+        let client = NflreadClient::new().unwrap();
+
+        fetch_cached(&client, &url, &dir, Duration::ZERO)
+            .await
+            .unwrap();
+        let path = fetch_cached(&client, &url, &dir, Duration::ZERO)
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].header("If-None-Match"), Some("\"v1\""));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "season\n2024\n");
+
+        let data = blocking(move || from_file(&path, FileFormat::Csv))
+            .await
+            .unwrap();
+        assert_eq!(data.shape(), (1, 1));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
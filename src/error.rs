@@ -0,0 +1,80 @@
+//! Error type returned throughout the crate.
+use crate::request::RateLimit;
+use polars::prelude::PolarsError;
+use reqwest::StatusCode;
+use std::path::PathBuf;
+use std::time::Duration;
+use thiserror::Error;
+use url::Url;
+
+/// Errors raised while building URLs, downloading and reading data.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The host answered with an unsuccessful status, e.g. 404 for a season which is not released yet.
+    ///
+    /// `retry_after` is the delay the host asked for with a `Retry-After` header on 429 and 503.
+    #[error("request to {url} failed with status {status}")]
+    Http {
+        url: Url,
+        status: StatusCode,
+        retry_after: Option<Duration>,
+    },
+
+    /// GitHub refused the request because the rate limit is exhausted.
+    ///
+    /// Anonymous requests share a low limit per IP address, set a GitHub token to raise it,
+    /// see `NflreadClient`.
+    #[error("rate limit exceeded for {url} with status {status}, {rate_limit}")]
+    RateLimited {
+        url: Url,
+        status: StatusCode,
+        rate_limit: Box<RateLimit>,
+    },
+
+    /// The GitHub token contains characters not allowed in a header.
+    #[error("invalid GitHub token")]
+    InvalidToken,
+
+    /// The request could not be sent or the response could not be received.
+    #[error("network error: {0}")]
+    Network(#[from] reqwest::Error),
+
+    /// Reading or writing a file failed.
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Parsing or writing the data failed.
+    #[error("polars error: {0}")]
+    Polars(#[from] PolarsError),
+
+    /// The response of the GitHub API could not be parsed.
+    #[error("invalid response: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// A URL could not be parsed.
+    #[error("invalid url: {0}")]
+    Url(#[from] url::ParseError),
+
+    /// The requested seasons are not valid for the downloader.
+    #[error("invalid season: {0}")]
+    InvalidSeason(String),
+
+    /// A file of a local data source does not exist, e.g. a season which was not synced.
+    #[error("{} does not exist", .0.display())]
+    MissingFile(PathBuf),
+
+    /// A URL does not point to an asset of a nflverse-data release.
+    #[error("no release asset for {0}")]
+    MissingAsset(Url),
+
+    /// The destination already exists and overwriting it was not requested.
+    #[error("{} already exists, use force = Some(true) to overwrite it", .0.display())]
+    FileExists(PathBuf),
+
+    /// The metadata of a cache entry could not be read.
+    #[error("malformed cache metadata at {}", .0.display())]
+    InvalidCache(PathBuf),
+}
+
+/// Result type using the crate's error.
+pub type Result<T> = std::result::Result<T, Error>;
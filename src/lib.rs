@@ -3,6 +3,23 @@
 //! This project is heavily inspired by the original modules by nflverse written in [R](https://github.com/nflverse/nflreadr) and
 //! [Python](https://github.com/nflverse/nflreadpy). Currently, we do not support the complete scope of the references due to this crate
 //! being work in process.
+pub mod batch;
+pub mod cache;
+pub mod client;
 pub mod downloader;
+pub mod error;
+pub mod progress;
+pub mod releases;
+pub mod request;
+pub mod sitrep;
+pub mod source;
 pub mod stats;
 pub mod utils;
+
+pub use client::NflreadClient;
+pub use error::{Error, Result};
+
+#[cfg(test)]
+mod test_server;
+#[cfg(test)]
+pub(crate) mod test_support;
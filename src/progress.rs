@@ -0,0 +1,52 @@
+//! Progress of downloads, reported to a hook set on the client.
+//!
+//! Set a hook with `NflreadClientBuilder::with_progress` to drive a progress bar or log
+//! large downloads. The crate itself does not depend on any UI, the hook receives plain
+//! byte and file counts. Hooks are called from the threads doing the downloads, so they
+//! should return quickly.
+use url::Url;
+
+/// Progress of a single file transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileProgress<'a> {
+    /// URL the file is downloaded from.
+    pub url: &'a Url,
+    /// Bytes received so far.
+    pub downloaded: u64,
+    /// Size of the file from the `Content-Length` header, None if the host did not send one.
+    pub total: Option<u64>,
+}
+
+/// Progress of a batch of downloads, see the `batch` module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchProgress {
+    /// Number of downloaders finished, successfully or not.
+    pub completed: usize,
+    /// Number of finished downloaders which failed.
+    pub failed: usize,
+    /// Number of downloaders in the batch.
+    pub total: usize,
+}
+
+/// Receives progress of downloads.
+///
+/// Both methods do nothing by default, implement the ones of interest. Closures taking a
+/// `&FileProgress` implement this trait for per-file progress.
+pub trait Progress: Send + Sync {
+    /// Called when a transfer starts and whenever bytes of it were received.
+    ///
+    /// A retried transfer starts again at zero bytes.
+    fn file(&self, _progress: &FileProgress) {}
+
+    /// Called whenever a downloader of a batch finished.
+    fn batch(&self, _progress: &BatchProgress) {}
+}
+
+impl<F> Progress for F
+where
+    F: Fn(&FileProgress) + Send + Sync,
+{
+    fn file(&self, progress: &FileProgress) {
+        self(progress)
+    }
+}
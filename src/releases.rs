@@ -0,0 +1,286 @@
+//! Discover the files published in the nflverse-data releases.
+//!
+//! Each dataset is published as a release of nflverse-data, e.g. `pbp` or `stats_player`,
+//! with one asset per file. Listing the assets of a release through the GitHub API shows
+//! which seasons and formats actually exist before downloading them, and when each file
+//! was last rebuilt by nflverse.
+use crate::client::{self, NflreadClient};
+use crate::downloader::{Downloader, FileFormat};
+use crate::error::{Error, Result};
+use crate::source;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::collections::HashMap;
+use url::Url;
+
+/// Maximum number of assets GitHub returns per page.
+const PER_PAGE: usize = 100;
+
+/// A file published in a release.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ReleaseAsset {
+    /// File name, e.g. `play_by_play_2024.parquet`.
+    pub name: String,
+    /// Size of the file in bytes.
+    pub size: u64,
+    /// Time the file was last updated.
+    pub updated_at: DateTime<Utc>,
+    /// URL the file is downloaded from.
+    #[serde(rename = "browser_download_url")]
+    pub download_url: Url,
+}
+
+impl ReleaseAsset {
+    /// File format of the asset, None if it is not a format this crate reads, e.g. `rds`.
+    pub fn format(&self) -> Option<FileFormat> {
+        FileFormat::from_name(&self.name)
+    }
+
+    /// Season of the asset, taken from a trailing year in its name, e.g. 2024 for
+    /// `play_by_play_2024.parquet`. None for assets spanning all seasons.
+    pub fn season(&self) -> Option<i32> {
+        season_of(&self.name)
+    }
+}
+
+/// Season of a release file, taken from a trailing year in its name, see `ReleaseAsset::season`.
+pub(crate) fn season_of(name: &str) -> Option<i32> {
+    let stem = name.split('.').next()?;
+    let (_, season) = stem.rsplit_once('_')?;
+
+    match season.len() {
+        4 => season.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Release as returned by the GitHub API, only the fields needed to list its assets.
+#[derive(Debug, Deserialize)]
+struct Release {
+    id: u64,
+}
+
+/// Lists the assets of a nflverse-data release.
+///
+/// # Arguments
+///
+/// * `tag` -   Tag of the release, e.g. `pbp`, `stats_player` or `rosters`.
+///
+/// Returns `Error::Http` with status 404 if there is no release with the tag.
+///
+/// # Examples
+///
+/// ```no_run
+/// use nflreadrs::downloader::FileFormat;
+/// use nflreadrs::releases::list_assets;
+///
+/// let seasons: Vec<i32> = list_assets("pbp")
+///     .unwrap()
+///     .iter()
+///     .filter(|asset| asset.format() == Some(FileFormat::Parquet))
+///     .filter_map(|asset| asset.season())
+///     .collect();
+/// ```
+pub fn list_assets(tag: &str) -> Result<Vec<ReleaseAsset>> {
+    client::default_client()?.list_assets(tag)
+}
+
+/// Release assets of the files a downloader spans, e.g. to show when they were last updated.
+///
+/// Returns one asset per URL of the downloader in the same order and `Error::MissingAsset`
+/// if a URL is not an asset of a nflverse-data release, e.g. a season not released yet.
+///
+/// # Arguments
+///
+/// * `downloader`  -   The struct relating to the desired stats. Needs to implement Downloader.
+///
+/// # Examples
+///
+/// ```no_run
+/// use nflreadrs::releases::assets_of;
+/// use nflreadrs::stats::PlayByPlay;
+///
+/// for asset in assets_of(&PlayByPlay::new(Some(vec![2023, 2024]))).unwrap() {
+///     println!("{} updated {}", asset.name, asset.updated_at);
+/// }
+/// ```
+pub fn assets_of<D>(downloader: &D) -> Result<Vec<ReleaseAsset>>
+where
+    D: Downloader,
+{
+    client::default_client()?.assets_of(downloader)
+}
+
+/// Release assets of a downloader with a client, see `assets_of`.
+///
+/// Each release is listed once, however many files of it the downloader spans.
+pub(crate) fn assets_of_from<D>(client: &NflreadClient, downloader: &D) -> Result<Vec<ReleaseAsset>>
+where
+    D: Downloader,
+{
+    let mut releases: HashMap<String, Vec<ReleaseAsset>> = HashMap::new();
+
+    downloader
+        .urls()?
+        .into_iter()
+        .map(|url| {
+            let Some((tag, name)) = url
+                .as_str()
+                .strip_prefix(source::DEFAULT_BASE_URL)
+                .and_then(|path| path.split_once('/'))
+            else {
+                return Err(Error::MissingAsset(url));
+            };
+
+            if !releases.contains_key(tag) {
+                releases.insert(tag.to_string(), list_assets_from(client, tag)?);
+            }
+
+            releases[tag]
+                .iter()
+                .find(|asset| asset.name == name)
+                .cloned()
+                .ok_or_else(|| Error::MissingAsset(url.clone()))
+        })
+        .collect()
+}
+
+/// Lists the assets of a release with a client, see `list_assets`.
+pub(crate) fn list_assets_from(client: &NflreadClient, tag: &str) -> Result<Vec<ReleaseAsset>> {
+    let api_url = client.api_url()?;
+    let release: Release = client.get_json(&api_url.join(&format!("releases/tags/{}", tag))?)?;
+
+    let mut assets = Vec::new();
+    for page in 1.. {
+        let mut url = api_url.join(&format!("releases/{}/assets", release.id))?;
+        url.query_pairs_mut()
+            .append_pair("per_page", &PER_PAGE.to_string())
+            .append_pair("page", &page.to_string());
+
+        let batch: Vec<ReleaseAsset> = client.get_json(&url)?;
+        let last = batch.len() < PER_PAGE;
+        assets.extend(batch);
+
+        if last {
+            break;
+        }
+    }
+
+    Ok(assets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats::PlayByPlay;
+    use crate::test_server::{Response, TestServer};
+
+    /// JSON of an asset as returned by the GitHub API.
+    fn asset_json(name: &str) -> String {
+        format!(
+            r#"{{"id": 1, "name": "{}", "size": 1024, "updated_at": "2025-01-01T12:00:00Z", "browser_download_url": "https://github.com/nflverse/nflverse-data/releases/download/pbp/{}"}}"#,
+            name, name
+        )
+    }
+
+    #[test]
+    fn test_season_and_format() {
+        let cases = [
+            // (name, expected season, expected format)
+            (
+                "play_by_play_2024.parquet",
+                Some(2024),
+                Some(FileFormat::Parquet),
+            ),
+            (
+                "stats_player_week_1999.csv.gz",
+                Some(1999),
+                Some(FileFormat::CsvGz),
+            ),
+            ("games.csv", None, Some(FileFormat::Csv)),
+            ("play_by_play_2024.rds", Some(2024), None),
+            ("stats_player_regpost.csv", None, Some(FileFormat::Csv)),
+        ];
+
+        for (name, season, format) in cases {
+            let asset: ReleaseAsset = serde_json::from_str(&asset_json(name)).unwrap();
+            assert_eq!(asset.season(), season, "{}", name);
+            assert_eq!(asset.format(), format, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_list_assets_pages() {
+        let server = TestServer::start(|request| match request.path.as_str() {
+            "/repos/releases/tags/pbp" => Response::ok(r#"{"id": 42, "tag_name": "pbp"}"#),
+            "/repos/releases/42/assets?per_page=100&page=1" => {
+                let assets: Vec<String> = (0..PER_PAGE)
+                    .map(|n| asset_json(&format!("play_by_play_{}.csv", 1900 + n)))
+                    .collect();
+                Response::ok(format!("[{}]", assets.join(",")))
+            }
+            "/repos/releases/42/assets?per_page=100&page=2" => {
+                Response::ok(format!("[{}]", asset_json("play_by_play_2024.parquet")))
+            }
+            _ => Response::status(404),
+        });
+        let client = NflreadClient::builder()
+            .with_api_url(server.url("/repos"))
+            .build()
+            .unwrap();
+
+        let assets = client.list_assets("pbp").unwrap();
+
+        assert_eq!(assets.len(), PER_PAGE + 1);
+        assert_eq!(assets[PER_PAGE].name, "play_by_play_2024.parquet");
+        assert_eq!(assets[PER_PAGE].size, 1024);
+        assert_eq!(
+            server.requests()[0].header("Accept"),
+            Some("application/vnd.github+json")
+        );
+    }
+
+    #[test]
+    fn test_assets_of_downloader() {
+        let server = TestServer::start(|request| match request.path.as_str() {
+            "/repos/releases/tags/pbp" => Response::ok(r#"{"id": 7}"#),
+            "/repos/releases/7/assets?per_page=100&page=1" => Response::ok(format!(
+                "[{},{}]",
+                asset_json("play_by_play_2023.csv"),
+                asset_json("play_by_play_2024.csv")
+            )),
+            _ => Response::status(404),
+        });
+        let client = NflreadClient::builder()
+            .with_api_url(server.url("/repos"))
+            .build()
+            .unwrap();
+
+        let assets = client
+            .assets_of(&PlayByPlay::new(Some(vec![2024, 2023])))
+            .unwrap();
+        let names: Vec<&str> = assets.iter().map(|asset| asset.name.as_str()).collect();
+
+        assert_eq!(names, ["play_by_play_2024.csv", "play_by_play_2023.csv"]);
+        assert_eq!(server.requests().len(), 2);
+
+        let result = client.assets_of(&PlayByPlay::new(Some(vec![2022])));
+        assert!(matches!(result, Err(Error::MissingAsset(_))));
+    }
+
+    #[test]
+    fn test_list_assets_unknown_tag() {
+        let server = TestServer::start(|_| Response::status(404));
+        let client = NflreadClient::builder()
+            .with_api_url(server.url("/repos"))
+            .build()
+            .unwrap();
+
+        let result = client.list_assets("unknown");
+
+        assert!(matches!(
+            result,
+            Err(Error::Http { status, .. }) if status == reqwest::StatusCode::NOT_FOUND
+        ));
+    }
+}
@@ -0,0 +1,502 @@
+//! Settings of the HTTP requests sent to the data host.
+//!
+//! Requests are retried with exponential backoff on transient failures, e.g. hiccups of
+//! the GitHub release CDN, and time out if the host does not connect or answer in time.
+//! The options set with `set_options` apply to all downloads, `pull_with` and
+//! `download_to_with` of the `downloader` module take options for a single call.
+//! Clients built with `NflreadClient::builder` carry their own options.
+use crate::error::Error;
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use std::fmt;
+use std::io;
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Connect timeout if not set otherwise.
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Read timeout if not set otherwise.
+pub const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Policy deciding if and when failed requests are retried.
+///
+/// The delay before the n-th retry is `initial_backoff * 2^(n-1)`, capped at `max_backoff`.
+/// With jitter, a random delay between half and the full backoff is used instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    statuses: Vec<StatusCode>,
+    network_errors: bool,
+}
+
+impl RetryPolicy {
+    /// Create the default retry policy.
+    ///
+    /// Requests are attempted up to three times, starting with a backoff of half a second.
+    /// Timeouts, connection failures, truncated transfers and the statuses 408, 429, 500,
+    /// 502, 503 and 504 are retried.
+    pub fn new() -> Self {
+        RetryPolicy::default()
+    }
+
+    /// Create a policy which never retries.
+    pub fn never() -> Self {
+        RetryPolicy::default().with_max_attempts(1)
+    }
+
+    /// Sets the maximum number of attempts including the first one. At least one attempt is made.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the backoff before the first retry and the maximum backoff.
+    pub fn with_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max.max(initial);
+        self
+    }
+
+    /// Enables or disables randomizing the backoff. Enabled by default.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the HTTP statuses which are retried.
+    pub fn with_statuses(mut self, statuses: Vec<StatusCode>) -> Self {
+        self.statuses = statuses;
+        self
+    }
+
+    /// Enables or disables retrying timeouts, connection failures and truncated transfers.
+    pub fn with_network_errors(mut self, network_errors: bool) -> Self {
+        self.network_errors = network_errors;
+        self
+    }
+
+    /// Maximum number of attempts including the first one.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Backoff before the given retry, the first retry being 1. Jitter is not applied.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// Returns true if the error of an attempt may be resolved by retrying.
+    pub fn is_retryable(&self, error: &Error) -> bool {
+        match error {
+            Error::Http { status, .. } => self.statuses.contains(status),
+            Error::RateLimited { .. } => true,
+            Error::Network(e) => {
+                self.network_errors
+                    && (e.is_timeout() || e.is_connect() || e.is_request() || e.is_body())
+            }
+            Error::Io(e) => self.network_errors && e.kind() == io::ErrorKind::UnexpectedEof,
+            _ => false,
+        }
+    }
+
+    /// Delay before retrying after a failed attempt, None if the error is to be returned.
+    ///
+    /// A `Retry-After` delay requested by the host is respected, rate limited requests wait
+    /// for the limit to reset. If that exceeds the maximum backoff, the request is not retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` -   Number of the failed attempt, the first attempt being 1.
+    /// * `error`   -   Error of the failed attempt.
+    pub(crate) fn delay(&self, attempt: u32, error: &Error) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.is_retryable(error) {
+            return None;
+        }
+
+        if let Error::Http {
+            retry_after: Some(retry_after),
+            ..
+        } = error
+        {
+            return (*retry_after <= self.max_backoff).then_some(*retry_after);
+        }
+
+        if let Error::RateLimited { rate_limit, .. } = error {
+            return rate_limit
+                .reset_in()
+                .filter(|reset_in| *reset_in <= self.max_backoff);
+        }
+
+        let backoff = self.backoff(attempt);
+        if self.jitter {
+            let half = backoff / 2;
+            Some(half + half.mul_f64(fastrand::f64()))
+        } else {
+            Some(backoff)
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    // Default retry policy, see `RetryPolicy::new`.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            statuses: vec![
+                StatusCode::REQUEST_TIMEOUT,
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            network_errors: true,
+        }
+    }
+}
+
+/// Options of the requests of a download.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestOptions {
+    retry: RetryPolicy,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+}
+
+impl RequestOptions {
+    /// Create the default request options.
+    ///
+    /// Uses the default retry policy, `DEFAULT_CONNECT_TIMEOUT` and `DEFAULT_READ_TIMEOUT`.
+    pub fn new() -> Self {
+        RequestOptions::default()
+    }
+
+    /// Sets the retry policy.
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Sets the time allowed to connect to the host. No timeout if None.
+    pub fn with_connect_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Sets the time allowed between receiving two parts of a response. No timeout if None.
+    ///
+    /// Large files are not limited in their total transfer time as long as data keeps arriving.
+    pub fn with_read_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.read_timeout = timeout;
+        self
+    }
+
+    /// Retry policy of the requests.
+    pub fn retry(&self) -> &RetryPolicy {
+        &self.retry
+    }
+
+    /// Time allowed to connect to the host.
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        self.connect_timeout
+    }
+
+    /// Time allowed between receiving two parts of a response.
+    pub fn read_timeout(&self) -> Option<Duration> {
+        self.read_timeout
+    }
+}
+
+impl Default for RequestOptions {
+    // Default request options, see `RequestOptions::new`.
+    fn default() -> Self {
+        Self {
+            retry: RetryPolicy::default(),
+            connect_timeout: Some(DEFAULT_CONNECT_TIMEOUT),
+            read_timeout: Some(DEFAULT_READ_TIMEOUT),
+        }
+    }
+}
+
+/// Options set through `set_options`, None if not set.
+static OPTIONS: RwLock<Option<RequestOptions>> = RwLock::new(None);
+
+/// Returns the options applied to all downloads, the default options if not set.
+pub fn options() -> RequestOptions {
+    OPTIONS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_default()
+}
+
+/// Sets the options applied to all downloads.
+pub fn set_options(options: RequestOptions) {
+    *OPTIONS.write().unwrap_or_else(|e| e.into_inner()) = Some(options);
+}
+
+/// Rate limit state reported by GitHub in the `x-ratelimit-*` response headers.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Maximum number of requests per window.
+    pub limit: Option<u64>,
+    /// Number of requests left in the current window.
+    pub remaining: Option<u64>,
+    /// Number of requests made in the current window.
+    pub used: Option<u64>,
+    /// Time the current window ends.
+    pub reset: Option<SystemTime>,
+    /// Rate limit resource the request counted against, e.g. `core`.
+    pub resource: Option<String>,
+}
+
+impl RateLimit {
+    /// Reads the rate limit headers of a response. None if there are none.
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let value = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(|value| value.trim().to_string())
+        };
+        let number = |name: &str| value(name).and_then(|value| value.parse().ok());
+
+        let rate_limit = Self {
+            limit: number("x-ratelimit-limit"),
+            remaining: number("x-ratelimit-remaining"),
+            used: number("x-ratelimit-used"),
+            reset: number("x-ratelimit-reset").map(|secs| UNIX_EPOCH + Duration::from_secs(secs)),
+            resource: value("x-ratelimit-resource"),
+        };
+
+        (rate_limit != Self::default()).then_some(rate_limit)
+    }
+
+    /// Returns true if no requests are left in the current window.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == Some(0)
+    }
+
+    /// Time until the current window ends, None if unknown.
+    pub fn reset_in(&self) -> Option<Duration> {
+        self.reset
+            .map(|reset| reset.duration_since(SystemTime::now()).unwrap_or_default())
+    }
+}
+
+impl fmt::Display for RateLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unknown = || "?".to_string();
+
+        write!(
+            f,
+            "{} of {} requests remaining",
+            self.remaining.map_or_else(unknown, |n| n.to_string()),
+            self.limit.map_or_else(unknown, |n| n.to_string())
+        )?;
+        if let Some(reset_in) = self.reset_in() {
+            write!(f, ", resets in {}s", reset_in.as_secs())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Delay requested by the `Retry-After` header of a 429 or 503 response.
+///
+/// The header holds either a number of seconds or an HTTP date.
+pub(crate) fn retry_after(status: StatusCode, headers: &HeaderMap) -> Option<Duration> {
+    if status != StatusCode::TOO_MANY_REQUESTS && status != StatusCode::SERVICE_UNAVAILABLE {
+        return None;
+    }
+
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(secs) = value.parse() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value)
+        .ok()?
+        .with_timezone(&Utc);
+    Some((date - Utc::now()).to_std().unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+    use url::Url;
+
+    /// Http error with the given status and `Retry-After` delay.
+    fn http_error(status: u16, retry_after: Option<Duration>) -> Error {
+        Error::Http {
+            url: Url::parse("https://example.com/pbp/play_by_play_2024.csv").unwrap(),
+            status: StatusCode::from_u16(status).unwrap(),
+            retry_after,
+        }
+    }
+
+    mod retry_policy_tests {
+        use super::*;
+
+        #[test]
+        fn test_backoff_doubles_up_to_max() {
+            let policy = RetryPolicy::new()
+                .with_backoff(Duration::from_millis(100), Duration::from_millis(350))
+                .with_jitter(false);
+
+            let cases = [
+                // (retry, expected backoff in ms)
+                (1, 100),
+                (2, 200),
+                (3, 350),
+                (40, 350),
+            ];
+
+            for (retry, exp) in cases {
+                assert_eq!(policy.backoff(retry), Duration::from_millis(exp));
+            }
+        }
+
+        #[test]
+        fn test_delay_respects_attempts_and_statuses() {
+            let policy = RetryPolicy::new().with_jitter(false);
+
+            assert_eq!(
+                policy.delay(1, &http_error(503, None)),
+                Some(Duration::from_millis(500))
+            );
+            assert_eq!(policy.delay(3, &http_error(503, None)), None);
+            assert_eq!(policy.delay(1, &http_error(404, None)), None);
+            assert_eq!(RetryPolicy::never().delay(1, &http_error(503, None)), None);
+        }
+
+        #[test]
+        fn test_delay_with_jitter() {
+            let policy =
+                RetryPolicy::new().with_backoff(Duration::from_secs(2), Duration::from_secs(2));
+
+            for _ in 0..20 {
+                let delay = policy.delay(1, &http_error(500, None)).unwrap();
+                assert!(delay >= Duration::from_secs(1) && delay <= Duration::from_secs(2));
+            }
+        }
+
+        #[test]
+        fn test_delay_uses_retry_after() {
+            let policy = RetryPolicy::new();
+
+            assert_eq!(
+                policy.delay(1, &http_error(429, Some(Duration::from_secs(7)))),
+                Some(Duration::from_secs(7))
+            );
+            assert_eq!(
+                policy.delay(1, &http_error(429, Some(Duration::from_secs(3600)))),
+                None
+            );
+        }
+
+        #[test]
+        fn test_truncated_transfer_is_retryable() {
+            let truncated = Error::Io(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated"));
+            let missing = Error::Io(io::Error::new(io::ErrorKind::NotFound, "missing"));
+
+            assert!(RetryPolicy::new().is_retryable(&truncated));
+            assert!(!RetryPolicy::new().is_retryable(&missing));
+            assert!(
+                !RetryPolicy::new()
+                    .with_network_errors(false)
+                    .is_retryable(&truncated)
+            );
+        }
+    }
+
+    mod rate_limit_tests {
+        use super::*;
+
+        #[test]
+        fn test_rate_limit_from_headers() {
+            let mut headers = HeaderMap::new();
+            assert_eq!(RateLimit::from_headers(&headers), None);
+
+            headers.insert("x-ratelimit-limit", HeaderValue::from_static("60"));
+            headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+            headers.insert("x-ratelimit-used", HeaderValue::from_static("60"));
+            headers.insert("x-ratelimit-reset", HeaderValue::from_static("1700000000"));
+            headers.insert("x-ratelimit-resource", HeaderValue::from_static("core"));
+
+            let rate_limit = RateLimit::from_headers(&headers).unwrap();
+            assert_eq!(rate_limit.limit, Some(60));
+            assert_eq!(rate_limit.used, Some(60));
+            assert_eq!(
+                rate_limit.reset,
+                Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+            );
+            assert_eq!(rate_limit.resource.as_deref(), Some("core"));
+            assert!(rate_limit.is_exhausted());
+            assert_eq!(rate_limit.reset_in(), Some(Duration::ZERO));
+            assert_eq!(
+                rate_limit.to_string(),
+                "0 of 60 requests remaining, resets in 0s"
+            );
+        }
+
+        #[test]
+        fn test_delay_waits_for_reset() {
+            let error = |reset_in: u64| Error::RateLimited {
+                url: Url::parse("https://api.github.com/repos").unwrap(),
+                status: StatusCode::FORBIDDEN,
+                rate_limit: Box::new(RateLimit {
+                    remaining: Some(0),
+                    reset: Some(SystemTime::now() + Duration::from_secs(reset_in)),
+                    ..RateLimit::default()
+                }),
+            };
+            let policy = RetryPolicy::new();
+
+            let delay = policy.delay(1, &error(10)).unwrap();
+            assert!(delay > Duration::from_secs(8) && delay <= Duration::from_secs(10));
+            assert_eq!(policy.delay(1, &error(3600)), None);
+        }
+    }
+
+    mod retry_after_tests {
+        use super::*;
+
+        #[test]
+        fn test_retry_after_seconds_and_date() {
+            let mut headers = HeaderMap::new();
+            headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+            assert_eq!(
+                retry_after(StatusCode::TOO_MANY_REQUESTS, &headers),
+                Some(Duration::from_secs(120))
+            );
+            assert_eq!(retry_after(StatusCode::BAD_GATEWAY, &headers), None);
+
+            headers.insert(
+                RETRY_AFTER,
+                HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+            );
+            assert_eq!(
+                retry_after(StatusCode::SERVICE_UNAVAILABLE, &headers),
+                Some(Duration::ZERO)
+            );
+
+            headers.insert(RETRY_AFTER, HeaderValue::from_static("soon"));
+            assert_eq!(retry_after(StatusCode::TOO_MANY_REQUESTS, &headers), None);
+        }
+    }
+}
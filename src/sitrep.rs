@@ -0,0 +1,223 @@
+//! Situation report of the environment the crate runs in.
+//!
+//! Like nflreadr's `nflverse_sitrep`, the report collects the configuration and state that
+//! commonly differ between machines: crate version, cache, data source, GitHub token,
+//! whether the data host can be reached and the current season and week. Problems are
+//! recorded in the report instead of returned as errors, so it can always be printed.
+use crate::cache;
+use crate::client::{self, NflreadClient};
+use crate::downloader::create_headers;
+use crate::error::Error;
+use crate::source;
+use crate::utils;
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+use url::Url;
+
+/// Files and total size of the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheUsage {
+    /// Number of cached files.
+    pub entries: usize,
+    /// Total size of the cached files in bytes.
+    pub size: u64,
+}
+
+/// Diagnostics report, see `sitrep`.
+///
+/// Fallible parts hold the error message if they could not be determined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sitrep {
+    /// Version of the crate.
+    pub version: &'static str,
+    /// Cache directory, see `cache::cache_dir`.
+    pub cache_dir: PathBuf,
+    /// Whether pulled files are cached.
+    pub cache_enabled: bool,
+    /// Maximum age of cache entries.
+    pub cache_max_age: Duration,
+    /// Files and size of the cache.
+    pub cache_usage: Result<CacheUsage, String>,
+    /// Base URL data is downloaded from, see `source::base_url`.
+    pub base_url: Result<Url, String>,
+    /// Whether requests to GitHub are authenticated with a token.
+    pub github_token: bool,
+    /// Whether the host of the base URL answered a request.
+    ///
+    /// Any answer counts, even an unsuccessful status, as it shows the host can be reached.
+    pub reachable: Result<(), String>,
+    /// Current season, see `utils::get_current_season`.
+    pub current_season: i32,
+    /// Current roster season, see `utils::get_current_season`.
+    pub current_roster_season: i32,
+    /// Current week, see `utils::get_current_week`.
+    pub current_week: i32,
+}
+
+/// Collects a diagnostics report with the default client.
+///
+/// Sends one request to the data host to check whether it can be reached.
+///
+/// # Examples
+///
+/// ```no_run
+/// use nflreadrs::sitrep::sitrep;
+///
+/// println!("{}", sitrep());
+/// ```
+pub fn sitrep() -> Sitrep {
+    match client::default_client() {
+        Ok(client) => client.sitrep(),
+        Err(e) => report(Err(e.to_string())),
+    }
+}
+
+/// Collects a diagnostics report with a client, see `sitrep`.
+pub(crate) fn sitrep_from(client: &NflreadClient) -> Sitrep {
+    report(Ok(client))
+}
+
+/// Collects a report, the client failed to build if Err.
+fn report(client: Result<&NflreadClient, String>) -> Sitrep {
+    let base_url = match client {
+        Ok(client) => client.base_url(),
+        Err(_) => source::base_url(),
+    }
+    .map_err(|e| e.to_string());
+    let reachable = match (&client, &base_url) {
+        (Err(e), _) | (_, Err(e)) => Err(e.clone()),
+        (Ok(client), Ok(base_url)) => reach(client, base_url),
+    };
+
+    Sitrep {
+        version: env!("CARGO_PKG_VERSION"),
+        cache_dir: cache::cache_dir(),
+        cache_enabled: cache::is_enabled(),
+        cache_max_age: cache::max_age(),
+        cache_usage: cache::list()
+            .map(|entries| CacheUsage {
+                entries: entries.len(),
+                size: entries.iter().map(|entry| entry.size).sum(),
+            })
+            .map_err(|e| e.to_string()),
+        base_url,
+        github_token: client.is_ok_and(NflreadClient::has_github_token),
+        reachable,
+        current_season: utils::get_current_season(None),
+        current_roster_season: utils::get_current_season(Some(true)),
+        current_week: utils::get_current_week(),
+    }
+}
+
+/// Checks whether the host of a base URL answers, local directories have to exist.
+///
+/// The request is sent once, without retries.
+fn reach(client: &NflreadClient, base_url: &Url) -> Result<(), String> {
+    if base_url.scheme() == "file" {
+        return match base_url.to_file_path() {
+            Ok(dir) if dir.is_dir() => Ok(()),
+            _ => Err(format!("{} is not a directory", base_url)),
+        };
+    }
+
+    match client.send(base_url, create_headers()) {
+        Ok(_) | Err(Error::Http { .. } | Error::RateLimited { .. }) => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+impl fmt::Display for Sitrep {
+    // Report as aligned lines of name and value.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "nflreadrs {}", self.version)?;
+        writeln!(f)?;
+        writeln!(f, "cache directory:  {}", self.cache_dir.display())?;
+        writeln!(
+            f,
+            "cache:            {}, max age {}s",
+            if self.cache_enabled {
+                "enabled"
+            } else {
+                "disabled"
+            },
+            self.cache_max_age.as_secs()
+        )?;
+        match &self.cache_usage {
+            Ok(usage) => writeln!(
+                f,
+                "cache usage:      {} files, {} bytes",
+                usage.entries, usage.size
+            )?,
+            Err(e) => writeln!(f, "cache usage:      error: {}", e)?,
+        }
+        match &self.base_url {
+            Ok(base_url) => writeln!(f, "base url:         {}", base_url)?,
+            Err(e) => writeln!(f, "base url:         error: {}", e)?,
+        }
+        writeln!(
+            f,
+            "github token:     {}",
+            if self.github_token { "set" } else { "not set" }
+        )?;
+        match &self.reachable {
+            Ok(()) => writeln!(f, "host:             reachable")?,
+            Err(e) => writeln!(f, "host:             unreachable: {}", e)?,
+        }
+        writeln!(
+            f,
+            "current season:   {} (rosters {}), week {}",
+            self.current_season, self.current_roster_season, self.current_week
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_server::{Response, TestServer};
+
+    #[test]
+    fn test_reachable_host() {
+        let server = TestServer::start(|_| Response::status(404));
+        let client = NflreadClient::builder()
+            .with_base_url(server.url("/releases"))
+            .build()
+            .unwrap();
+
+        let report = client.sitrep();
+
+        assert_eq!(report.reachable, Ok(()));
+        assert_eq!(report.base_url, Ok(server.url("/releases/")));
+        assert_eq!(server.requests()[0].path, "/releases/");
+    }
+
+    #[test]
+    fn test_unreachable_host() {
+        let client = NflreadClient::builder()
+            .with_base_url(Url::parse("http://nflverse.invalid/releases").unwrap())
+            .build()
+            .unwrap();
+
+        let report = client.sitrep();
+
+        assert!(report.reachable.is_err());
+        assert!(report.to_string().contains("host:             unreachable"));
+    }
+
+    #[test]
+    fn test_missing_local_dir() {
+        let dir = std::env::temp_dir().join(format!("nflreadrs-test-{}", uuid::Uuid::new_v4()));
+        let client = NflreadClient::builder()
+            .with_base_url(source::dir_url(&dir).unwrap())
+            .build()
+            .unwrap();
+
+        assert!(client.sitrep().reachable.is_err());
+
+        std::fs::create_dir_all(&dir).unwrap();
+        assert_eq!(client.sitrep().reachable, Ok(()));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
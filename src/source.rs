@@ -0,0 +1,174 @@
+//! Location the nflverse data releases are downloaded from.
+//!
+//! Downloaders build their URLs against `DEFAULT_BASE_URL`, the release downloads of
+//! nflverse-data on GitHub. Before sending a request these URLs are resolved against the
+//! configured base URL, so all downloaders can be served by a mirror or a local file server.
+//! The base URL is set for all downloads with `set_base_url` or the `NFLREADRS_BASE_URL`
+//! environment variable, or per client with `NflreadClientBuilder::with_base_url`.
+//!
+//! A `file://` base URL reads the data from a local directory laid out like the releases,
+//! e.g. `pbp/play_by_play_2024.parquet`, without any requests. Local files are read in
+//! place and not cached.
+use crate::error::Result;
+use std::env;
+use std::io;
+use std::path::{self, Path};
+use std::sync::RwLock;
+use url::Url;
+
+/// Release downloads of nflverse-data on GitHub.
+pub const DEFAULT_BASE_URL: &str = "https://github.com/nflverse/nflverse-data/releases/download/";
+
+/// GitHub API of the nflverse-data repository, used to list release assets.
+pub const DEFAULT_API_URL: &str = "https://api.github.com/repos/nflverse/nflverse-data/";
+
+/// Environment variable to set the base URL, a URL or a path to a local directory.
+pub const BASE_URL_ENV: &str = "NFLREADRS_BASE_URL";
+
+static BASE_URL: RwLock<Option<Url>> = RwLock::new(None);
+
+/// Returns the base URL data is downloaded from.
+///
+/// The URL set by `set_base_url` takes precedence over the `NFLREADRS_BASE_URL`
+/// environment variable. If neither is set, `DEFAULT_BASE_URL` is used.
+///
+/// Returns `Error::Url` if the environment variable is neither a valid URL nor an existing directory.
+pub fn base_url() -> Result<Url> {
+    if let Some(url) = BASE_URL.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+        return Ok(url.clone());
+    }
+
+    let url = match env::var(BASE_URL_ENV) {
+        Ok(value) if Path::new(value.trim()).is_dir() => dir_url(value.trim())?,
+        Ok(value) if !value.trim().is_empty() => Url::parse(value.trim())?,
+        _ => Url::parse(DEFAULT_BASE_URL)?,
+    };
+
+    Ok(as_base(url))
+}
+
+/// `file://` base URL of a local directory laid out like the releases.
+///
+/// Relative paths are resolved against the current directory.
+///
+/// # Examples
+///
+/// ```no_run
+/// use nflreadrs::source;
+///
+/// source::set_base_url(Some(source::dir_url("/data/nflverse").unwrap()));
+/// ```
+pub fn dir_url(dir: impl AsRef<Path>) -> Result<Url> {
+    let dir = path::absolute(dir)?;
+
+    Url::from_directory_path(&dir).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("not a directory path: {}", dir.display()),
+        )
+        .into()
+    })
+}
+
+/// Sets the base URL, overriding the `NFLREADRS_BASE_URL` environment variable.
+///
+/// Releases are expected under the same paths as on GitHub, e.g. `pbp/play_by_play_2024.csv`.
+/// None resets to the environment variable or `DEFAULT_BASE_URL`.
+///
+/// # Examples
+///
+/// ```
+/// use nflreadrs::source;
+/// use url::Url;
+///
+/// source::set_base_url(Some(Url::parse("http://mirror.local/nflverse").unwrap()));
+///
+/// # assert_eq!(source::base_url().unwrap().as_str(), "http://mirror.local/nflverse/");
+/// # source::set_base_url(None);
+/// ```
+pub fn set_base_url(url: Option<Url>) {
+    *BASE_URL.write().unwrap_or_else(|e| e.into_inner()) = url.map(as_base);
+}
+
+/// Adds a trailing slash to the path of a URL so relative paths are joined below it.
+pub(crate) fn as_base(mut url: Url) -> Url {
+    if !url.path().ends_with('/') {
+        let path = format!("{}/", url.path());
+        url.set_path(&path);
+    }
+
+    url
+}
+
+/// URL of a release file on GitHub, e.g. `pbp/play_by_play_2024.csv`.
+pub(crate) fn release_url(path: &str) -> Result<Url> {
+    Ok(Url::parse(DEFAULT_BASE_URL)?.join(path)?)
+}
+
+/// Resolves a URL below `DEFAULT_BASE_URL` against another base URL.
+///
+/// URLs pointing elsewhere are returned unchanged.
+pub(crate) fn rebase(url: &Url, base: &Url) -> Result<Url> {
+    match url.as_str().strip_prefix(DEFAULT_BASE_URL) {
+        Some(path) => Ok(base.join(path)?),
+        None => Ok(url.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_base_adds_trailing_slash() {
+        let cases = [
+            // (url, expected)
+            ("http://mirror.local", "http://mirror.local/"),
+            (
+                "http://mirror.local/nflverse",
+                "http://mirror.local/nflverse/",
+            ),
+            (
+                "http://mirror.local/nflverse/",
+                "http://mirror.local/nflverse/",
+            ),
+        ];
+
+        for (url, exp) in cases {
+            assert_eq!(as_base(Url::parse(url).unwrap()).as_str(), exp);
+        }
+    }
+
+    #[test]
+    fn test_rebase() {
+        let base = Url::parse("http://127.0.0.1:8080/releases/").unwrap();
+        let cases = [
+            // (url, expected)
+            (
+                "https://github.com/nflverse/nflverse-data/releases/download/pbp/play_by_play_2024.csv",
+                "http://127.0.0.1:8080/releases/pbp/play_by_play_2024.csv",
+            ),
+            (
+                "https://github.com/nflverse/nflverse-data/releases/download/schedules/games.parquet",
+                "http://127.0.0.1:8080/releases/schedules/games.parquet",
+            ),
+            (
+                "https://example.com/pbp/play_by_play_2024.csv",
+                "https://example.com/pbp/play_by_play_2024.csv",
+            ),
+        ];
+
+        for (url, exp) in cases {
+            let rebased = rebase(&Url::parse(url).unwrap(), &base).unwrap();
+            assert_eq!(rebased.as_str(), exp);
+        }
+    }
+
+    #[test]
+    fn test_rebase_default_base_is_identity() {
+        let url = release_url("rosters/roster_2024.csv").unwrap();
+        let base = Url::parse(DEFAULT_BASE_URL).unwrap();
+
+        assert_eq!(rebase(&url, &base).unwrap(), url);
+    }
+}
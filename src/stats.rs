@@ -1,7 +1,10 @@
 //! Generate configuration for a wanted download.
-use crate::downloader::Downloader;
-use crate::utils::{self, get_current_season};
-use anyhow::Result;
+use crate::downloader::{Downloader, FileFormat, RowFilter};
+use crate::error::{Error, Result};
+use crate::releases;
+use crate::source;
+use crate::utils;
+use polars::prelude::{Column, DataFrame, DataType};
 use std::default::Default;
 use strum::Display;
 use url::Url;
@@ -9,7 +12,7 @@ use url::Url;
 /// Summary levels describing the scope of the data.
 ///
 /// Some stat downloaders need a specified scope.
-#[derive(Debug, Display)]
+#[derive(Debug, Display, Clone, Copy)]
 pub enum SummaryLevel {
     Week,
     Reg,
@@ -17,12 +20,139 @@ pub enum SummaryLevel {
     RegPost,
 }
 
+/// Stat types of Next Gen Stats.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum NgsStatType {
+    Passing,
+    Rushing,
+    Receiving,
+}
+
+/// Summary levels of Next Gen Stats.
+///
+/// nflverse publishes the season aggregates as rows with week 0 next to the weekly rows.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum NgsSummaryLevel {
+    Week,
+    Season,
+}
+
+/// Window of seasons a dataset is available for.
+///
+/// The window starts at the first season nflverse provides and ends at the current season.
+/// Roster based datasets use the roster logic of `utils::get_current_season` for the end.
+struct SeasonWindow {
+    first: i32,
+    roster: bool,
+}
+
+/// Seasons of play by play data.
+const PLAY_BY_PLAY_SEASONS: SeasonWindow = SeasonWindow {
+    first: 1999,
+    roster: false,
+};
+
+/// Seasons of player stats.
+const PLAYER_STATS_SEASONS: SeasonWindow = SeasonWindow {
+    first: 1999,
+    roster: false,
+};
+
+/// Seasons of snap counts.
+const SNAP_COUNT_SEASONS: SeasonWindow = SeasonWindow {
+    first: 2012,
+    roster: false,
+};
+
+/// Seasons of Next Gen Stats.
+const NEXT_GEN_STATS_SEASONS: SeasonWindow = SeasonWindow {
+    first: 2016,
+    roster: false,
+};
+
+/// Seasons of team stats.
+const TEAM_STATS_SEASONS: SeasonWindow = SeasonWindow {
+    first: 1999,
+    roster: false,
+};
+
+/// Seasons of rosters.
+const ROSTER_SEASONS: SeasonWindow = SeasonWindow {
+    first: 1920,
+    roster: true,
+};
+
+/// Seasons of weekly rosters.
+const WEEKLY_ROSTER_SEASONS: SeasonWindow = SeasonWindow {
+    first: 2002,
+    roster: true,
+};
+
+/// Seasons of depth charts.
+const DEPTH_CHART_SEASONS: SeasonWindow = SeasonWindow {
+    first: 2001,
+    roster: false,
+};
+
+/// Seasons of injury reports.
+const INJURY_SEASONS: SeasonWindow = SeasonWindow {
+    first: 2009,
+    roster: false,
+};
+
+impl SeasonWindow {
+    /// Latest season of the window.
+    fn last(&self) -> i32 {
+        utils::get_current_season(Some(self.roster))
+    }
+
+    /// Returns the season if it lies within the window.
+    fn check(&self, season: i32) -> Result<i32> {
+        let last = self.last();
+
+        if (self.first..=last).contains(&season) {
+            Ok(season)
+        } else {
+            Err(Error::InvalidSeason(format!(
+                "{} is not available, seasons range from {} to {}",
+                season, self.first, last
+            )))
+        }
+    }
+
+    /// Resolves the requested seasons to a single season, the current season if None.
+    ///
+    /// Returns an error if multiple seasons were requested as these span multiple files.
+    fn single(&self, seasons: &Option<Vec<i32>>) -> Result<i32> {
+        match seasons {
+            None => Ok(utils::get_current_season(None)),
+            Some(v) => match v.as_slice() {
+                [season] => self.check(*season),
+                _ => Err(Error::InvalidSeason(format!(
+                    "seasons {:?} span multiple files, use urls()",
+                    v
+                ))),
+            },
+        }
+    }
+
+    /// Resolves all requested seasons, the current season if None.
+    fn all(&self, seasons: &Option<Vec<i32>>) -> Result<Vec<i32>> {
+        match seasons {
+            None => Ok(vec![utils::get_current_season(None)]),
+            Some(v) if v.is_empty() => Err(Error::InvalidSeason("no seasons given".to_string())),
+            Some(v) => v.iter().map(|season| self.check(*season)).collect(),
+        }
+    }
+}
+
 /// Downloader for team stats.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TeamStats {
     seasons: Option<Vec<i32>>,
     summary_level: SummaryLevel,
-    base_url: &'static str,
+    path: &'static str,
+    format: FileFormat,
 }
 
 impl TeamStats {
@@ -32,12 +162,11 @@ impl TeamStats {
     ///
     /// # Arguments
     ///
-    /// * `seasons` -   Current season if None. A vector of the desired season if Some.
+    /// * `seasons` -   Current season if None. A vector of the desired seasons if Some.
     /// * `summary_level`   -   Summary level of the data to retrieve.
     ///
-    /// # Panics
-    ///
-    /// Panics if a vector of length greater than one is passed. These vectors are not supported yet.
+    /// If multiple seasons are given, `url` returns an error and `urls` has to be used.
+    /// Pulling such a downloader stacks all seasons into one DataFrame.
     ///
     /// # Examples
     ///
@@ -56,34 +185,59 @@ impl TeamStats {
         Self {
             seasons,
             summary_level,
-            base_url: "https://github.com/nflverse/nflverse-data/releases/download/stats_team/",
+            path: "stats_team/",
+            format: FileFormat::default(),
         }
     }
+
+    /// Sets the file format to download, CSV if not set.
+    pub fn with_format(mut self, format: FileFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// URL to the team stats of a single season.
+    fn season_url(&self, season: i32) -> Result<Url> {
+        let summary = self.summary_level.to_string().to_lowercase();
+
+        let url = format!(
+            "{}stats_team_{}_{}.{}",
+            self.path,
+            summary,
+            season,
+            self.format.extension()
+        );
+
+        source::release_url(&url)
+    }
 }
 
 impl Downloader for TeamStats {
     /// Returns a valid URL to the download destination.
+    ///
+    /// Returns an error if multiple seasons were requested, use `urls` instead,
+    /// or if the season is not available.
     fn url(&self) -> Result<Url> {
-        let summary = self.summary_level.to_string().to_lowercase();
-
-        let seasons = match &self.seasons {
-            None => utils::get_current_season(None),
-            Some(v) => match v.len() {
-                1 => v[0],
-                _ => anyhow::bail!("Unhandled season case {:?}", self.seasons),
-            },
-        };
-
-        let url = format!("{}stats_team_{}_{}.csv", self.base_url, summary, seasons);
+        self.season_url(TEAM_STATS_SEASONS.single(&self.seasons)?)
+    }
 
-        Ok(Url::parse(&url)?)
+    /// Returns valid URLs to the download destinations, one per season.
+    ///
+    /// Returns an error if any of the seasons is not available.
+    fn urls(&self) -> Result<Vec<Url>> {
+        TEAM_STATS_SEASONS
+            .all(&self.seasons)?
+            .into_iter()
+            .map(|season| self.season_url(season))
+            .collect()
     }
 }
 
 /// Downloader for schedules.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Schedules {
-    base_url: &'static str,
+    path: &'static str,
+    format: FileFormat,
 }
 
 impl Schedules {
@@ -106,13 +260,20 @@ impl Schedules {
     pub fn new() -> Self {
         Schedules::default()
     }
+
+    /// Sets the file format to download, CSV if not set.
+    pub fn with_format(mut self, format: FileFormat) -> Self {
+        self.format = format;
+        self
+    }
 }
 
 impl Default for Schedules {
     // Default constructor for schedules downloader.
     fn default() -> Self {
         Self {
-            base_url: "https://github.com/nflverse/nflverse-data/releases/download/schedules/games.csv",
+            path: "schedules/games",
+            format: FileFormat::default(),
         }
     }
 }
@@ -120,17 +281,20 @@ impl Default for Schedules {
 impl Downloader for Schedules {
     /// Returns a valid URL to the download destination.
     ///
-    /// Here the download URL is the base url as the source does not provide seasons or summary levels
+    /// Here the download URL is the release path with the file extension as the source does not provide seasons or summary levels
     fn url(&self) -> Result<Url> {
-        Ok(Url::parse(self.base_url)?)
+        let url = format!("{}.{}", self.path, self.format.extension());
+
+        source::release_url(&url)
     }
 }
 
 /// Downloader for play by play data.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PlayByPlay {
-    seasons: Option<i32>,
-    base_url: &'static str,
+    seasons: Option<Vec<i32>>,
+    path: &'static str,
+    format: FileFormat,
 }
 
 impl PlayByPlay {
@@ -140,14 +304,14 @@ impl PlayByPlay {
     ///
     /// # Arguments
     ///
-    /// * `seasons` -   Current season if None. Given season if Some.
+    /// * `seasons` -   Current season if None. A vector of the desired seasons if Some.
     ///
     /// # Examples
     ///
     /// ```
     /// use nflreadrs::stats::PlayByPlay;
     ///
-    /// let seasons: Option<i32> = Some(2025);
+    /// let seasons: Option<Vec<i32>> = Some(vec![2025]);
     ///
     /// let play_by_play_dl = PlayByPlay::new(seasons);
     ///
@@ -155,31 +319,61 @@ impl PlayByPlay {
     /// # use nflreadrs::downloader::Downloader;
     /// # assert_eq!(play_by_play_dl.url().unwrap(), Url::parse("https://github.com/nflverse/nflverse-data/releases/download/pbp/play_by_play_2025.csv").unwrap())
     /// ```
-    pub fn new(seasons: Option<i32>) -> Self {
+    pub fn new(seasons: Option<Vec<i32>>) -> Self {
         Self {
             seasons,
-            base_url: "https://github.com/nflverse/nflverse-data/releases/download/pbp/",
+            path: "pbp/",
+            format: FileFormat::default(),
         }
     }
+
+    /// Sets the file format to download, CSV if not set.
+    pub fn with_format(mut self, format: FileFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// URL to the play by play data of a single season.
+    fn season_url(&self, season: i32) -> Result<Url> {
+        let url = format!(
+            "{}play_by_play_{}.{}",
+            self.path,
+            season,
+            self.format.extension()
+        );
+
+        source::release_url(&url)
+    }
 }
 
 impl Downloader for PlayByPlay {
     /// Returns a valid URL to the download destination.
+    ///
+    /// Returns an error if multiple seasons were requested, use `urls` instead,
+    /// or if the season is not available.
     fn url(&self) -> Result<Url> {
-        let seasons = self.seasons.unwrap_or(utils::get_current_season(None));
-
-        let url = format!("{}play_by_play_{}.csv", self.base_url, seasons);
+        self.season_url(PLAY_BY_PLAY_SEASONS.single(&self.seasons)?)
+    }
 
-        Ok(Url::parse(&url)?)
+    /// Returns valid URLs to the download destinations, one per season.
+    ///
+    /// Returns an error if any of the seasons is not available.
+    fn urls(&self) -> Result<Vec<Url>> {
+        PLAY_BY_PLAY_SEASONS
+            .all(&self.seasons)?
+            .into_iter()
+            .map(|season| self.season_url(season))
+            .collect()
     }
 }
 
 /// Downloader for player stats.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PlayerStats {
-    seasons: Option<i32>,
+    seasons: Option<Vec<i32>>,
     summary_level: SummaryLevel,
-    base_url: &'static str,
+    path: &'static str,
+    format: FileFormat,
 }
 
 impl PlayerStats {
@@ -189,7 +383,7 @@ impl PlayerStats {
     ///
     /// # Arguments
     ///
-    /// * `seasons` -   Current season if None. Given season if Some.
+    /// * `seasons` -   Current season if None. A vector of the desired seasons if Some.
     /// * `summary_level`   -   Summary level of the data to retrieve.
     ///
     /// # Examples
@@ -197,7 +391,7 @@ impl PlayerStats {
     /// ```
     /// use nflreadrs::stats::{SummaryLevel, PlayerStats};
     ///
-    /// let seasons: Option<i32> = Some(2025);
+    /// let seasons: Option<Vec<i32>> = Some(vec![2025]);
     ///
     /// let player_stats_dl = PlayerStats::new(seasons, SummaryLevel::Reg);
     ///
@@ -205,31 +399,264 @@ impl PlayerStats {
     /// # use nflreadrs::downloader::Downloader;
     /// # assert_eq!(player_stats_dl.url().unwrap(), Url::parse("https://github.com/nflverse/nflverse-data/releases/download/stats_player/stats_player_reg_2025.csv").unwrap())
     /// ```
-    pub fn new(seasons: Option<i32>, summary_level: SummaryLevel) -> Self {
+    pub fn new(seasons: Option<Vec<i32>>, summary_level: SummaryLevel) -> Self {
         Self {
             seasons,
             summary_level,
-            base_url: "https://github.com/nflverse/nflverse-data/releases/download/stats_player/",
+            path: "stats_player/",
+            format: FileFormat::default(),
         }
     }
+
+    /// Sets the file format to download, CSV if not set.
+    pub fn with_format(mut self, format: FileFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// URL to the player stats of a single season.
+    fn season_url(&self, season: i32) -> Result<Url> {
+        let summary = self.summary_level.to_string().to_lowercase();
+
+        let url = format!(
+            "{}stats_player_{}_{}.{}",
+            self.path,
+            summary,
+            season,
+            self.format.extension()
+        );
+
+        source::release_url(&url)
+    }
 }
 
 impl Downloader for PlayerStats {
     /// Returns a valid URL to the download destination.
+    ///
+    /// Returns an error if multiple seasons were requested, use `urls` instead,
+    /// or if the season is not available.
     fn url(&self) -> Result<Url> {
-        let summary = self.summary_level.to_string().to_lowercase();
+        self.season_url(PLAYER_STATS_SEASONS.single(&self.seasons)?)
+    }
+
+    /// Returns valid URLs to the download destinations, one per season.
+    ///
+    /// Returns an error if any of the seasons is not available.
+    fn urls(&self) -> Result<Vec<Url>> {
+        PLAYER_STATS_SEASONS
+            .all(&self.seasons)?
+            .into_iter()
+            .map(|season| self.season_url(season))
+            .collect()
+    }
+}
+
+/// Downloader for snap counts.
+///
+/// Offense, defense and special teams snaps and snap shares per player and game, sourced
+/// from Pro Football Reference. Players are identified by `pfr_player_id`.
+#[derive(Debug, Clone)]
+pub struct SnapCounts {
+    seasons: Option<Vec<i32>>,
+    path: &'static str,
+    format: FileFormat,
+}
+
+impl SnapCounts {
+    /// Create a new snap counts downloader.
+    ///
+    /// This method is used to construct a downloader for snap counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `seasons` -   Current season if None. A vector of the desired seasons if Some.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nflreadrs::stats::SnapCounts;
+    ///
+    /// let seasons: Option<Vec<i32>> = Some(vec![2024]);
+    ///
+    /// let snap_counts_dl = SnapCounts::new(seasons);
+    ///
+    /// # use url::Url;
+    /// # use nflreadrs::downloader::Downloader;
+    /// # assert_eq!(snap_counts_dl.url().unwrap(), Url::parse("https://github.com/nflverse/nflverse-data/releases/download/snap_counts/snap_counts_2024.csv").unwrap())
+    /// ```
+    pub fn new(seasons: Option<Vec<i32>>) -> Self {
+        Self {
+            seasons,
+            path: "snap_counts/",
+            format: FileFormat::default(),
+        }
+    }
+
+    /// Sets the file format to download, CSV if not set.
+    pub fn with_format(mut self, format: FileFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// URL to the snap counts of a single season.
+    fn season_url(&self, season: i32) -> Result<Url> {
+        let url = format!(
+            "{}snap_counts_{}.{}",
+            self.path,
+            season,
+            self.format.extension()
+        );
+
+        source::release_url(&url)
+    }
+}
+
+impl Downloader for SnapCounts {
+    /// Returns a valid URL to the download destination.
+    ///
+    /// Returns an error if multiple seasons were requested, use `urls` instead,
+    /// or if the season is not available.
+    fn url(&self) -> Result<Url> {
+        self.season_url(SNAP_COUNT_SEASONS.single(&self.seasons)?)
+    }
+
+    /// Returns valid URLs to the download destinations, one per season.
+    ///
+    /// Returns an error if any of the seasons is not available.
+    fn urls(&self) -> Result<Vec<Url>> {
+        SNAP_COUNT_SEASONS
+            .all(&self.seasons)?
+            .into_iter()
+            .map(|season| self.season_url(season))
+            .collect()
+    }
+}
+
+/// Seasons kept of the Next Gen Stats file spanning all seasons.
+#[derive(Debug, Clone)]
+enum NgsSeasons {
+    All,
+    /// Current season if None, see `NextGenStats::new`.
+    Selected(Option<Vec<i32>>),
+}
 
-        let seasons = self.seasons.unwrap_or(get_current_season(None));
-        let url = format!("{}stats_player_{}_{}.csv", self.base_url, summary, seasons);
+/// Downloader for Next Gen Stats.
+///
+/// nflverse publishes one file per stat type spanning all seasons since 2016. The file is
+/// downloaded once and the rows of the requested seasons are kept, unless all seasons
+/// are requested with `NextGenStats::all_seasons`.
+#[derive(Debug, Clone)]
+pub struct NextGenStats {
+    seasons: NgsSeasons,
+    stat_type: NgsStatType,
+    summary_level: Option<NgsSummaryLevel>,
+    path: &'static str,
+    format: FileFormat,
+}
+
+impl NextGenStats {
+    /// Create a new Next Gen Stats downloader.
+    ///
+    /// This method is used to construct a downloader for Next Gen Stats of selected seasons.
+    ///
+    /// # Arguments
+    ///
+    /// * `seasons` -   Current season if None. A vector of the desired seasons if Some.
+    /// * `stat_type`   -   Stat type of the data to retrieve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nflreadrs::stats::{NextGenStats, NgsStatType, NgsSummaryLevel};
+    ///
+    /// let seasons: Option<Vec<i32>> = Some(vec![2023, 2024]);
+    ///
+    /// let ngs_dl = NextGenStats::new(seasons, NgsStatType::Passing)
+    ///     .with_summary_level(NgsSummaryLevel::Season);
+    ///
+    /// # use url::Url;
+    /// # use nflreadrs::downloader::Downloader;
+    /// # assert_eq!(ngs_dl.url().unwrap(), Url::parse("https://github.com/nflverse/nflverse-data/releases/download/nextgen_stats/ngs_passing.csv").unwrap())
+    /// ```
+    pub fn new(seasons: Option<Vec<i32>>, stat_type: NgsStatType) -> Self {
+        Self {
+            seasons: NgsSeasons::Selected(seasons),
+            stat_type,
+            summary_level: None,
+            path: "nextgen_stats/",
+            format: FileFormat::default(),
+        }
+    }
 
-        Ok(Url::parse(&url)?)
+    /// Create a new Next Gen Stats downloader keeping all seasons.
+    ///
+    /// # Arguments
+    ///
+    /// * `stat_type`   -   Stat type of the data to retrieve.
+    pub fn all_seasons(stat_type: NgsStatType) -> Self {
+        Self {
+            seasons: NgsSeasons::All,
+            ..Self::new(None, stat_type)
+        }
+    }
+
+    /// Keeps only the rows of a summary level, weekly rows and season aggregates if not set.
+    pub fn with_summary_level(mut self, summary_level: NgsSummaryLevel) -> Self {
+        self.summary_level = Some(summary_level);
+        self
+    }
+
+    /// Sets the file format to download, CSV if not set.
+    pub fn with_format(mut self, format: FileFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+impl Downloader for NextGenStats {
+    /// Returns a valid URL to the download destination.
+    ///
+    /// The file spans all seasons. Returns an error if any of the requested seasons is not available.
+    fn url(&self) -> Result<Url> {
+        if let NgsSeasons::Selected(seasons) = &self.seasons {
+            NEXT_GEN_STATS_SEASONS.all(seasons)?;
+        }
+
+        let stat_type = self.stat_type.to_string().to_lowercase();
+        let url = format!("{}ngs_{}.{}", self.path, stat_type, self.format.extension());
+
+        source::release_url(&url)
+    }
+
+    /// Keeps the rows of the requested seasons and summary level.
+    fn filter(&self) -> Option<RowFilter> {
+        let seasons = match &self.seasons {
+            NgsSeasons::All => None,
+            NgsSeasons::Selected(None) => Some(vec![utils::get_current_season(None)]),
+            NgsSeasons::Selected(Some(seasons)) => Some(seasons.clone()),
+        }
+        .map(|seasons| RowFilter::new("season", seasons.into_iter().map(i64::from)));
+
+        let weeks: Option<Vec<i64>> = match self.summary_level {
+            None => None,
+            Some(NgsSummaryLevel::Season) => Some(vec![0]),
+            Some(NgsSummaryLevel::Week) => Some((1..=utils::LAST_WEEK as i64).collect()),
+        };
+
+        match (seasons, weeks) {
+            (Some(filter), Some(weeks)) => Some(filter.and("week", weeks)),
+            (Some(filter), None) => Some(filter),
+            (None, Some(weeks)) => Some(RowFilter::new("week", weeks)),
+            (None, None) => None,
+        }
     }
 }
 
 /// Downloader for teams.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Teams {
-    base_url: &'static str,
+    path: &'static str,
+    format: FileFormat,
 }
 
 impl Teams {
@@ -252,13 +679,20 @@ impl Teams {
     pub fn new() -> Self {
         Teams::default()
     }
+
+    /// Sets the file format to download, CSV if not set.
+    pub fn with_format(mut self, format: FileFormat) -> Self {
+        self.format = format;
+        self
+    }
 }
 
 impl Default for Teams {
     // Default constructor for Teams downloader.
     fn default() -> Self {
         Self {
-            base_url: "https://github.com/nflverse/nflverse-data/releases/download/teams/teams_colors_logos.csv",
+            path: "teams/teams_colors_logos",
+            format: FileFormat::default(),
         }
     }
 }
@@ -266,16 +700,19 @@ impl Default for Teams {
 impl Downloader for Teams {
     /// Returns a valid URL to the download destination.
     ///
-    /// Here the download URL is the base url as the source does not provide seasons or summary levels
+    /// Here the download URL is the release path with the file extension as the source does not provide seasons or summary levels
     fn url(&self) -> Result<Url> {
-        Ok(Url::parse(self.base_url)?)
+        let url = format!("{}.{}", self.path, self.format.extension());
+
+        source::release_url(&url)
     }
 }
 
 /// Downloader for Players.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Players {
-    base_url: &'static str,
+    path: &'static str,
+    format: FileFormat,
 }
 
 impl Players {
@@ -298,13 +735,20 @@ impl Players {
     pub fn new() -> Self {
         Players::default()
     }
+
+    /// Sets the file format to download, CSV if not set.
+    pub fn with_format(mut self, format: FileFormat) -> Self {
+        self.format = format;
+        self
+    }
 }
 
 impl Default for Players {
     // Default constructor for Players downloader.
     fn default() -> Self {
         Self {
-            base_url: "https://github.com/nflverse/nflverse-data/releases/download/players/players.csv",
+            path: "players/players",
+            format: FileFormat::default(),
         }
     }
 }
@@ -312,17 +756,20 @@ impl Default for Players {
 impl Downloader for Players {
     /// Returns a valid URL to the download destination.
     ///
-    /// Here the download URL is the base url as the source does not provide seasons or summary levels
+    /// Here the download URL is the release path with the file extension as the source does not provide seasons or summary levels
     fn url(&self) -> Result<Url> {
-        Ok(Url::parse(self.base_url)?)
+        let url = format!("{}.{}", self.path, self.format.extension());
+
+        source::release_url(&url)
     }
 }
 
-/// Downloader for play by play data.
-#[derive(Debug)]
+/// Downloader for rosters.
+#[derive(Debug, Clone)]
 pub struct Rosters {
-    seasons: Option<i32>,
-    base_url: &'static str,
+    seasons: Option<Vec<i32>>,
+    path: &'static str,
+    format: FileFormat,
 }
 
 impl Rosters {
@@ -332,14 +779,14 @@ impl Rosters {
     ///
     /// # Arguments
     ///
-    /// * `seasons` -   Current season if None. Given season if Some.
+    /// * `seasons` -   Current season if None. A vector of the desired seasons if Some.
     ///
     /// # Examples
     ///
     /// ```
     /// use nflreadrs::stats::Rosters;
     ///
-    /// let seasons: Option<i32> = Some(2025);
+    /// let seasons: Option<Vec<i32>> = Some(vec![2025]);
     ///
     /// let rosters_dl = Rosters::new(seasons);
     ///
@@ -347,22 +794,422 @@ impl Rosters {
     /// # use nflreadrs::downloader::Downloader;
     /// # assert_eq!(rosters_dl.url().unwrap(), Url::parse("https://github.com/nflverse/nflverse-data/releases/download/rosters/roster_2025.csv").unwrap())
     /// ```
-    pub fn new(seasons: Option<i32>) -> Self {
+    pub fn new(seasons: Option<Vec<i32>>) -> Self {
         Self {
             seasons,
-            base_url: "https://github.com/nflverse/nflverse-data/releases/download/rosters/",
+            path: "rosters/",
+            format: FileFormat::default(),
         }
     }
+
+    /// Sets the file format to download, CSV if not set.
+    pub fn with_format(mut self, format: FileFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// URL to the roster of a single season.
+    fn season_url(&self, season: i32) -> Result<Url> {
+        let url = format!("{}roster_{}.{}", self.path, season, self.format.extension());
+
+        source::release_url(&url)
+    }
 }
 
 impl Downloader for Rosters {
     /// Returns a valid URL to the download destination.
+    ///
+    /// Returns an error if multiple seasons were requested, use `urls` instead,
+    /// or if the season is not available.
+    fn url(&self) -> Result<Url> {
+        self.season_url(ROSTER_SEASONS.single(&self.seasons)?)
+    }
+
+    /// Returns valid URLs to the download destinations, one per season.
+    ///
+    /// Returns an error if any of the seasons is not available.
+    fn urls(&self) -> Result<Vec<Url>> {
+        ROSTER_SEASONS
+            .all(&self.seasons)?
+            .into_iter()
+            .map(|season| self.season_url(season))
+            .collect()
+    }
+}
+
+/// Downloader for weekly rosters.
+///
+/// Weekly rosters list every player of a team per week with their roster status,
+/// e.g. `ACT`, `RES`, `INA` or practice squad.
+#[derive(Debug, Clone)]
+pub struct WeeklyRosters {
+    seasons: Option<Vec<i32>>,
+    weeks: Option<Vec<i32>>,
+    path: &'static str,
+    format: FileFormat,
+}
+
+impl WeeklyRosters {
+    /// Create a new weekly rosters downloader.
+    ///
+    /// This method is used to construct a downloader for weekly rosters.
+    ///
+    /// # Arguments
+    ///
+    /// * `seasons` -   Current season if None. A vector of the desired seasons if Some.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nflreadrs::stats::WeeklyRosters;
+    ///
+    /// let seasons: Option<Vec<i32>> = Some(vec![2024]);
+    ///
+    /// let weekly_rosters_dl = WeeklyRosters::new(seasons).with_weeks(vec![1, 2]);
+    ///
+    /// # use url::Url;
+    /// # use nflreadrs::downloader::Downloader;
+    /// # assert_eq!(weekly_rosters_dl.url().unwrap(), Url::parse("https://github.com/nflverse/nflverse-data/releases/download/weekly_rosters/roster_weekly_2024.csv").unwrap())
+    /// ```
+    pub fn new(seasons: Option<Vec<i32>>) -> Self {
+        Self {
+            seasons,
+            weeks: None,
+            path: "weekly_rosters/",
+            format: FileFormat::default(),
+        }
+    }
+
+    /// Keeps only the given weeks of each season, all weeks if not set.
+    pub fn with_weeks(mut self, weeks: Vec<i32>) -> Self {
+        self.weeks = Some(weeks);
+        self
+    }
+
+    /// Sets the file format to download, CSV if not set.
+    pub fn with_format(mut self, format: FileFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// URL to the weekly rosters of a single season.
+    fn season_url(&self, season: i32) -> Result<Url> {
+        let url = format!(
+            "{}roster_weekly_{}.{}",
+            self.path,
+            season,
+            self.format.extension()
+        );
+
+        source::release_url(&url)
+    }
+}
+
+impl Downloader for WeeklyRosters {
+    /// Returns a valid URL to the download destination.
+    ///
+    /// Returns an error if multiple seasons were requested, use `urls` instead,
+    /// or if the season is not available.
+    fn url(&self) -> Result<Url> {
+        self.season_url(WEEKLY_ROSTER_SEASONS.single(&self.seasons)?)
+    }
+
+    /// Returns valid URLs to the download destinations, one per season.
+    ///
+    /// Returns an error if any of the seasons is not available.
+    fn urls(&self) -> Result<Vec<Url>> {
+        WEEKLY_ROSTER_SEASONS
+            .all(&self.seasons)?
+            .into_iter()
+            .map(|season| self.season_url(season))
+            .collect()
+    }
+
+    /// Keeps the rows of the selected weeks.
+    fn filter(&self) -> Option<RowFilter> {
+        self.weeks
+            .as_ref()
+            .map(|weeks| RowFilter::new("week", weeks.iter().map(|week| *week as i64)))
+    }
+}
+
+/// Downloader for depth charts.
+///
+/// Up to 2024 nflverse published weekly snapshots of the depth charts, since 2025 daily
+/// snapshots with a timestamp. Both are normalized into the same columns:
+///
+/// | Column           | Type   | Until 2024       | Since 2025      |
+/// |------------------|--------|------------------|-----------------|
+/// | `season`         | Int32  | `season`         | file season     |
+/// | `week`           | Int32  | `week`           | null            |
+/// | `dt`             | String | null             | `dt`            |
+/// | `team`           | String | `club_code`      | `team`          |
+/// | `gsis_id`        | String | `gsis_id`        | `gsis_id`       |
+/// | `player_name`    | String | `full_name`      | `player_name`   |
+/// | `position_group` | String | `formation`      | `pos_grp`       |
+/// | `position`       | String | `depth_position` | `pos_abb`       |
+/// | `depth`          | Int32  | `depth_team`     | `pos_rank`      |
+///
+/// All other columns, e.g. `game_type`, `jersey_number` or `espn_id`, are kept as published
+/// after these and are null in the seasons of the other schema. The weekly `position`, the
+/// roster position of the player, is kept as `roster_position`.
+#[derive(Debug, Clone)]
+pub struct DepthCharts {
+    seasons: Option<Vec<i32>>,
+    path: &'static str,
+    format: FileFormat,
+}
+
+impl DepthCharts {
+    /// Create a new depth charts downloader.
+    ///
+    /// This method is used to construct a downloader for depth charts.
+    ///
+    /// # Arguments
+    ///
+    /// * `seasons` -   Current season if None. A vector of the desired seasons if Some.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nflreadrs::stats::DepthCharts;
+    ///
+    /// let seasons: Option<Vec<i32>> = Some(vec![2024]);
+    ///
+    /// let depth_charts_dl = DepthCharts::new(seasons);
+    ///
+    /// # use url::Url;
+    /// # use nflreadrs::downloader::Downloader;
+    /// # assert_eq!(depth_charts_dl.url().unwrap(), Url::parse("https://github.com/nflverse/nflverse-data/releases/download/depth_charts/depth_charts_2024.csv").unwrap())
+    /// ```
+    pub fn new(seasons: Option<Vec<i32>>) -> Self {
+        Self {
+            seasons,
+            path: "depth_charts/",
+            format: FileFormat::default(),
+        }
+    }
+
+    /// Sets the file format to download, CSV if not set.
+    pub fn with_format(mut self, format: FileFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// URL to the depth charts of a single season.
+    fn season_url(&self, season: i32) -> Result<Url> {
+        let url = format!(
+            "{}depth_charts_{}.{}",
+            self.path,
+            season,
+            self.format.extension()
+        );
+
+        source::release_url(&url)
+    }
+
+    /// Normalizes weekly snapshots, published until 2024.
+    fn normalize_weekly(data: &DataFrame) -> Result<DataFrame> {
+        let mut data = data.clone();
+        if data.column("position").is_ok() {
+            data.rename("position", "roster_position".into())?;
+        }
+        let data = &data;
+
+        let columns = vec![
+            renamed(data, "season", "season", &DataType::Int32)?,
+            renamed(data, "week", "week", &DataType::Int32)?,
+            Column::full_null("dt".into(), data.height(), &DataType::String),
+            renamed(data, "club_code", "team", &DataType::String)?,
+            renamed(data, "gsis_id", "gsis_id", &DataType::String)?,
+            renamed(data, "full_name", "player_name", &DataType::String)?,
+            renamed(data, "formation", "position_group", &DataType::String)?,
+            renamed(data, "depth_position", "position", &DataType::String)?,
+            renamed(data, "depth_team", "depth", &DataType::Int32)?,
+        ];
+        let mapped = [
+            "season",
+            "week",
+            "club_code",
+            "gsis_id",
+            "full_name",
+            "formation",
+            "depth_position",
+            "depth_team",
+        ];
+
+        with_published(columns, data, &mapped)
+    }
+
+    /// Normalizes daily snapshots, published since 2025, which lack the season.
+    fn normalize_daily(data: &DataFrame, season: i32) -> Result<DataFrame> {
+        let columns = vec![
+            Column::new("season".into(), vec![season; data.height()]),
+            Column::full_null("week".into(), data.height(), &DataType::Int32),
+            renamed(data, "dt", "dt", &DataType::String)?,
+            renamed(data, "team", "team", &DataType::String)?,
+            renamed(data, "gsis_id", "gsis_id", &DataType::String)?,
+            renamed(data, "player_name", "player_name", &DataType::String)?,
+            renamed(data, "pos_grp", "position_group", &DataType::String)?,
+            renamed(data, "pos_abb", "position", &DataType::String)?,
+            renamed(data, "pos_rank", "depth", &DataType::Int32)?,
+        ];
+        let mapped = [
+            "dt",
+            "team",
+            "gsis_id",
+            "player_name",
+            "pos_grp",
+            "pos_abb",
+            "pos_rank",
+        ];
+
+        with_published(columns, data, &mapped)
+    }
+}
+
+/// Normalized columns followed by the published columns which were not mapped to them.
+fn with_published(
+    mut columns: Vec<Column>,
+    data: &DataFrame,
+    mapped: &[&str],
+) -> Result<DataFrame> {
+    columns.extend(
+        data.get_columns()
+            .iter()
+            .filter(|column| !mapped.contains(&column.name().as_str()))
+            .cloned(),
+    );
+
+    Ok(DataFrame::new(columns)?)
+}
+
+/// Column of a DataFrame cast to a type and renamed.
+fn renamed(data: &DataFrame, from: &str, to: &str, dtype: &DataType) -> Result<Column> {
+    Ok(data.column(from)?.cast(dtype)?.with_name(to.into()))
+}
+
+impl Downloader for DepthCharts {
+    /// Returns a valid URL to the download destination.
+    ///
+    /// Returns an error if multiple seasons were requested, use `urls` instead,
+    /// or if the season is not available.
     fn url(&self) -> Result<Url> {
-        let seasons = self.seasons.unwrap_or(utils::get_current_season(None));
+        self.season_url(DEPTH_CHART_SEASONS.single(&self.seasons)?)
+    }
 
-        let url = format!("{}roster_{}.csv", self.base_url, seasons);
+    /// Returns valid URLs to the download destinations, one per season.
+    ///
+    /// Returns an error if any of the seasons is not available.
+    fn urls(&self) -> Result<Vec<Url>> {
+        DEPTH_CHART_SEASONS
+            .all(&self.seasons)?
+            .into_iter()
+            .map(|season| self.season_url(season))
+            .collect()
+    }
+
+    /// Normalizes weekly and daily snapshots into the same columns, see `DepthCharts`.
+    ///
+    /// Daily snapshots are recognized by their `dt` column, their season is taken from the file name.
+    fn normalize(&self, url: &Url, data: &DataFrame) -> Result<Option<DataFrame>> {
+        if data.column("dt").is_err() {
+            return DepthCharts::normalize_weekly(data).map(Some);
+        }
+
+        let season = url
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .and_then(releases::season_of)
+            .ok_or_else(|| Error::InvalidSeason(format!("no season in {}", url)))?;
+
+        DepthCharts::normalize_daily(data, season).map(Some)
+    }
 
-        Ok(Url::parse(&url)?)
+    fn normalizes(&self, _url: &Url) -> bool {
+        true
+    }
+}
+
+/// Downloader for injury reports.
+///
+/// The official injury reports with report status and practice participation per player
+/// and week. Rows are keyed by `gsis_id`, `season` and `week`, which matches `player_id`,
+/// `season` and `week` of `PlayerStats` on weekly summary level.
+#[derive(Debug, Clone)]
+pub struct Injuries {
+    seasons: Option<Vec<i32>>,
+    path: &'static str,
+    format: FileFormat,
+}
+
+impl Injuries {
+    /// Create a new injury reports downloader.
+    ///
+    /// This method is used to construct a downloader for injury reports.
+    ///
+    /// # Arguments
+    ///
+    /// * `seasons` -   Current season if None. A vector of the desired seasons if Some.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nflreadrs::stats::Injuries;
+    ///
+    /// let seasons: Option<Vec<i32>> = Some((2020..=2024).collect());
+    ///
+    /// let injuries_dl = Injuries::new(seasons);
+    ///
+    /// # use url::Url;
+    /// # use nflreadrs::downloader::Downloader;
+    /// # assert_eq!(injuries_dl.urls().unwrap()[0], Url::parse("https://github.com/nflverse/nflverse-data/releases/download/injuries/injuries_2020.csv").unwrap())
+    /// ```
+    pub fn new(seasons: Option<Vec<i32>>) -> Self {
+        Self {
+            seasons,
+            path: "injuries/",
+            format: FileFormat::default(),
+        }
+    }
+
+    /// Sets the file format to download, CSV if not set.
+    pub fn with_format(mut self, format: FileFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// URL to the injury reports of a single season.
+    fn season_url(&self, season: i32) -> Result<Url> {
+        let url = format!(
+            "{}injuries_{}.{}",
+            self.path,
+            season,
+            self.format.extension()
+        );
+
+        source::release_url(&url)
+    }
+}
+
+impl Downloader for Injuries {
+    /// Returns a valid URL to the download destination.
+    ///
+    /// Returns an error if multiple seasons were requested, use `urls` instead,
+    /// or if the season is not available.
+    fn url(&self) -> Result<Url> {
+        self.season_url(INJURY_SEASONS.single(&self.seasons)?)
+    }
+
+    /// Returns valid URLs to the download destinations, one per season.
+    ///
+    /// Returns an error if any of the seasons is not available.
+    fn urls(&self) -> Result<Vec<Url>> {
+        INJURY_SEASONS
+            .all(&self.seasons)?
+            .into_iter()
+            .map(|season| self.season_url(season))
+            .collect()
     }
 }
 
@@ -370,6 +1217,56 @@ impl Downloader for Rosters {
 mod tests {
     use super::*;
 
+    mod season_window_tests {
+        use super::*;
+
+        #[test]
+        fn test_check_bounds() {
+            let current = utils::get_current_season(None);
+            let cases = [
+                // (season, expected to be valid)
+                (1998, false),
+                (1999, true),
+                (current, true),
+                (current + 1, false),
+                (1850, false),
+            ];
+
+            for (season, exp) in cases {
+                assert_eq!(PLAY_BY_PLAY_SEASONS.check(season).is_ok(), exp);
+            }
+        }
+
+        #[test]
+        fn test_roster_window_uses_roster_logic() {
+            assert_eq!(ROSTER_SEASONS.last(), utils::get_current_season(Some(true)));
+            assert!(ROSTER_SEASONS.check(1920).is_ok());
+            assert!(ROSTER_SEASONS.check(1919).is_err());
+        }
+
+        #[test]
+        fn test_out_of_range_seasons() {
+            let next = utils::get_current_season(Some(true)) + 1;
+
+            assert!(matches!(
+                PlayByPlay::new(Some(vec![1850])).url(),
+                Err(Error::InvalidSeason(_))
+            ));
+            assert!(matches!(
+                PlayerStats::new(Some(vec![1998]), SummaryLevel::Reg).url(),
+                Err(Error::InvalidSeason(_))
+            ));
+            assert!(matches!(
+                TeamStats::new(Some(vec![2020, next]), SummaryLevel::Week).urls(),
+                Err(Error::InvalidSeason(_))
+            ));
+            assert!(matches!(
+                Rosters::new(Some(vec![next])).url(),
+                Err(Error::InvalidSeason(_))
+            ));
+        }
+    }
+
     mod team_stats_downloader_tests {
         use super::*;
 
@@ -377,7 +1274,7 @@ mod tests {
         fn test_correct_url_various_seasons_and_summary_levels() {
             let cases = [
                 // (summary level, season, expected url ending)
-                (SummaryLevel::Post, 2035, "post_2035"),
+                (SummaryLevel::Post, 2023, "post_2023"),
                 (SummaryLevel::Reg, 2005, "reg_2005"),
                 (SummaryLevel::Week, 2017, "week_2017"),
                 (SummaryLevel::RegPost, 2011, "regpost_2011"),
@@ -404,12 +1301,28 @@ mod tests {
             assert_eq!(team_stats.url().unwrap(), expected_url);
         }
 
-        // TODO: This behavior will be changed
         #[test]
-        fn test_correct_url_season_vec() {
+        fn test_correct_urls_season_vec() {
+            let base = "https://github.com/nflverse/nflverse-data/releases/download/stats_team/stats_team_";
             let team_stats = TeamStats::new(Some(vec![2000, 2012]), SummaryLevel::Post);
-            let url = team_stats.url();
-            assert!(url.is_err());
+            let expected_urls = vec![
+                Url::parse(&format!("{}post_2000.csv", base)).unwrap(),
+                Url::parse(&format!("{}post_2012.csv", base)).unwrap(),
+            ];
+            assert_eq!(team_stats.urls().unwrap(), expected_urls);
+            assert!(matches!(team_stats.url(), Err(Error::InvalidSeason(_))));
+        }
+
+        #[test]
+        fn test_correct_urls_single_season() {
+            let team_stats = TeamStats::new(Some(vec![2012]), SummaryLevel::Week);
+            assert_eq!(team_stats.urls().unwrap(), vec![team_stats.url().unwrap()]);
+        }
+
+        #[test]
+        fn test_urls_empty_season_vec() {
+            let team_stats = TeamStats::new(Some(vec![]), SummaryLevel::Week);
+            assert!(team_stats.urls().is_err());
         }
     }
 
@@ -421,9 +1334,13 @@ mod tests {
             let cases = [(2025, "2025.csv"), (2006, "2006.csv")];
 
             for (season, exp) in cases {
-                let play_by_play = PlayByPlay::new(Some(season));
-                let expected =
-                    Url::parse(&format!("{}play_by_play_{}", play_by_play.base_url, exp)).unwrap();
+                let play_by_play = PlayByPlay::new(Some(vec![season]));
+                let expected = Url::parse(&format!(
+                    "{}pbp/play_by_play_{}",
+                    source::DEFAULT_BASE_URL,
+                    exp
+                ))
+                .unwrap();
                 assert_eq!(play_by_play.url().unwrap(), expected);
             }
         }
@@ -440,6 +1357,44 @@ mod tests {
             .unwrap();
             assert_eq!(play_by_play.url().unwrap(), expected_url);
         }
+
+        #[test]
+        fn test_correct_urls_season_range() {
+            let base = "https://github.com/nflverse/nflverse-data/releases/download/pbp/";
+            let play_by_play = PlayByPlay::new(Some((2010..=2012).collect()));
+            let expected_urls: Vec<Url> = (2010..=2012)
+                .map(|season| Url::parse(&format!("{}play_by_play_{}.csv", base, season)).unwrap())
+                .collect();
+            assert_eq!(play_by_play.urls().unwrap(), expected_urls);
+            assert!(play_by_play.url().is_err());
+        }
+
+        #[test]
+        fn test_correct_url_various_formats() {
+            let cases = [
+                (FileFormat::Csv, "play_by_play_2024.csv"),
+                (FileFormat::CsvGz, "play_by_play_2024.csv.gz"),
+                (FileFormat::Parquet, "play_by_play_2024.parquet"),
+            ];
+
+            for (format, exp) in cases {
+                let play_by_play = PlayByPlay::new(Some(vec![2024])).with_format(format);
+                let expected =
+                    Url::parse(&format!("{}pbp/{}", source::DEFAULT_BASE_URL, exp)).unwrap();
+                assert_eq!(play_by_play.url().unwrap(), expected);
+            }
+        }
+    }
+
+    mod teams_downloader_tests {
+        use super::*;
+
+        #[test]
+        fn test_correct_url_parquet() {
+            let teams = Teams::new().with_format(FileFormat::Parquet);
+            let expected_url = Url::parse("https://github.com/nflverse/nflverse-data/releases/download/teams/teams_colors_logos.parquet").unwrap();
+            assert_eq!(teams.url().unwrap(), expected_url);
+        }
     }
 
     mod player_stats_downloader_tests {
@@ -449,7 +1404,7 @@ mod tests {
         fn test_correct_url_various_seasons_and_summary_levels() {
             let cases = [
                 // (summary level, season, expected url ending)
-                (SummaryLevel::Post, 2035, "post_2035"),
+                (SummaryLevel::Post, 2023, "post_2023"),
                 (SummaryLevel::Reg, 2005, "reg_2005"),
                 (SummaryLevel::Week, 2017, "week_2017"),
                 (SummaryLevel::RegPost, 2011, "regpost_2011"),
@@ -457,7 +1412,7 @@ mod tests {
             let base = "https://github.com/nflverse/nflverse-data/releases/download/stats_player/stats_player_";
 
             for (sum_lvl, season, exp) in cases {
-                let team_stats = PlayerStats::new(Some(season), sum_lvl);
+                let team_stats = PlayerStats::new(Some(vec![season]), sum_lvl);
                 let expected_url = Url::parse(&format!("{}{}.csv", base, exp)).unwrap();
                 assert_eq!(team_stats.url().unwrap(), expected_url);
             }
@@ -475,5 +1430,300 @@ mod tests {
             .unwrap();
             assert_eq!(team_stats.url().unwrap(), expected_url);
         }
+
+        #[test]
+        fn test_correct_urls_season_range() {
+            let base = "https://github.com/nflverse/nflverse-data/releases/download/stats_player/stats_player_";
+            let player_stats = PlayerStats::new(Some(vec![2019, 2020]), SummaryLevel::Reg);
+            let expected_urls = vec![
+                Url::parse(&format!("{}reg_2019.csv", base)).unwrap(),
+                Url::parse(&format!("{}reg_2020.csv", base)).unwrap(),
+            ];
+            assert_eq!(player_stats.urls().unwrap(), expected_urls);
+        }
+    }
+
+    mod snap_counts_downloader_tests {
+        use super::*;
+
+        #[test]
+        fn test_correct_urls_season_range() {
+            let base = "https://github.com/nflverse/nflverse-data/releases/download/snap_counts/";
+            let snap_counts =
+                SnapCounts::new(Some(vec![2012, 2013])).with_format(FileFormat::Parquet);
+            let expected_urls = vec![
+                Url::parse(&format!("{}snap_counts_2012.parquet", base)).unwrap(),
+                Url::parse(&format!("{}snap_counts_2013.parquet", base)).unwrap(),
+            ];
+            assert_eq!(snap_counts.urls().unwrap(), expected_urls);
+        }
+
+        #[test]
+        fn test_seasons_before_2012() {
+            let snap_counts = SnapCounts::new(Some(vec![2011]));
+            assert!(matches!(snap_counts.url(), Err(Error::InvalidSeason(_))));
+        }
+    }
+
+    mod next_gen_stats_downloader_tests {
+        use super::*;
+
+        #[test]
+        fn test_correct_url_various_stat_types() {
+            let cases = [
+                // (stat type, expected url ending)
+                (NgsStatType::Passing, "ngs_passing.parquet"),
+                (NgsStatType::Rushing, "ngs_rushing.parquet"),
+                (NgsStatType::Receiving, "ngs_receiving.parquet"),
+            ];
+            let base = "https://github.com/nflverse/nflverse-data/releases/download/nextgen_stats/";
+
+            for (stat_type, exp) in cases {
+                let ngs = NextGenStats::new(Some(vec![2020, 2021]), stat_type)
+                    .with_format(FileFormat::Parquet);
+                let expected_url = Url::parse(&format!("{}{}", base, exp)).unwrap();
+                assert_eq!(ngs.urls().unwrap(), vec![expected_url]);
+            }
+        }
+
+        #[test]
+        fn test_seasons_before_2016() {
+            let ngs = NextGenStats::new(Some(vec![2015, 2016]), NgsStatType::Rushing);
+            assert!(matches!(ngs.url(), Err(Error::InvalidSeason(_))));
+            assert_eq!(ngs.filter(), Some(RowFilter::new("season", [2015, 2016])));
+        }
+
+        #[test]
+        fn test_filter_seasons_and_summary_level() {
+            let weeks: Vec<i64> = (1..=22).collect();
+            let cases = [
+                // (downloader, expected filter)
+                (
+                    NextGenStats::new(Some(vec![2023, 2024]), NgsStatType::Passing),
+                    Some(RowFilter::new("season", [2023, 2024])),
+                ),
+                (
+                    NextGenStats::new(Some(vec![2024]), NgsStatType::Passing)
+                        .with_summary_level(NgsSummaryLevel::Season),
+                    Some(RowFilter::new("season", [2024]).and("week", [0])),
+                ),
+                (
+                    NextGenStats::all_seasons(NgsStatType::Passing)
+                        .with_summary_level(NgsSummaryLevel::Week),
+                    Some(RowFilter::new("week", weeks)),
+                ),
+                (NextGenStats::all_seasons(NgsStatType::Passing), None),
+            ];
+
+            for (ngs, exp) in cases {
+                assert_eq!(ngs.filter(), exp);
+            }
+        }
+    }
+
+    mod rosters_downloader_tests {
+        use super::*;
+
+        #[test]
+        fn test_correct_url_seasons_none() {
+            let base = "https://github.com/nflverse/nflverse-data/releases/download/rosters/";
+            let rosters = Rosters::new(None);
+            let expected_url = Url::parse(&format!(
+                "{}roster_{}.csv",
+                base,
+                utils::get_current_season(None)
+            ))
+            .unwrap();
+            assert_eq!(rosters.url().unwrap(), expected_url);
+            assert_eq!(rosters.urls().unwrap(), vec![expected_url]);
+        }
+
+        #[test]
+        fn test_correct_urls_season_range() {
+            let base = "https://github.com/nflverse/nflverse-data/releases/download/rosters/";
+            let rosters = Rosters::new(Some(vec![1999, 2000]));
+            let expected_urls = vec![
+                Url::parse(&format!("{}roster_1999.csv", base)).unwrap(),
+                Url::parse(&format!("{}roster_2000.csv", base)).unwrap(),
+            ];
+            assert_eq!(rosters.urls().unwrap(), expected_urls);
+        }
+
+        #[test]
+        fn test_urls_empty_season_vec() {
+            let rosters = Rosters::new(Some(vec![]));
+            assert!(matches!(rosters.urls(), Err(Error::InvalidSeason(_))));
+        }
+    }
+
+    mod weekly_rosters_downloader_tests {
+        use super::*;
+        use polars::df;
+
+        #[test]
+        fn test_correct_urls_season_range() {
+            let base =
+                "https://github.com/nflverse/nflverse-data/releases/download/weekly_rosters/";
+            let weekly_rosters =
+                WeeklyRosters::new(Some(vec![2002, 2003])).with_format(FileFormat::Parquet);
+            let expected_urls = vec![
+                Url::parse(&format!("{}roster_weekly_2002.parquet", base)).unwrap(),
+                Url::parse(&format!("{}roster_weekly_2003.parquet", base)).unwrap(),
+            ];
+            assert_eq!(weekly_rosters.urls().unwrap(), expected_urls);
+        }
+
+        #[test]
+        fn test_seasons_before_2002() {
+            let weekly_rosters = WeeklyRosters::new(Some(vec![2001]));
+            assert!(matches!(weekly_rosters.url(), Err(Error::InvalidSeason(_))));
+        }
+
+        #[test]
+        fn test_filter_weeks() {
+            let data = df!(
+                "week" => [1, 1, 2, 3],
+                "status" => ["ACT", "RES", "ACT", "INA"]
+            )
+            .unwrap();
+
+            assert_eq!(WeeklyRosters::new(None).filter(), None);
+
+            let filter = WeeklyRosters::new(None)
+                .with_weeks(vec![1, 3])
+                .filter()
+                .unwrap();
+            let filtered = filter.apply(&data).unwrap();
+
+            assert_eq!(filtered.shape(), (3, 2));
+            assert!(
+                filtered
+                    .column("week")
+                    .unwrap()
+                    .i32()
+                    .unwrap()
+                    .into_iter()
+                    .all(|week| week != Some(2))
+            );
+        }
+    }
+
+    mod depth_charts_downloader_tests {
+        use super::*;
+        use crate::downloader::stack;
+        use polars::df;
+
+        #[test]
+        fn test_correct_urls_season_range() {
+            let base = "https://github.com/nflverse/nflverse-data/releases/download/depth_charts/";
+            let depth_charts = DepthCharts::new(Some(vec![2024, 2025]));
+            let expected_urls = vec![
+                Url::parse(&format!("{}depth_charts_2024.csv", base)).unwrap(),
+                Url::parse(&format!("{}depth_charts_2025.csv", base)).unwrap(),
+            ];
+            assert_eq!(depth_charts.urls().unwrap(), expected_urls);
+            assert!(DepthCharts::new(Some(vec![2000])).url().is_err());
+        }
+
+        #[test]
+        fn test_normalize_weekly_and_daily() {
+            let depth_charts = DepthCharts::new(Some(vec![2024, 2025]));
+            let urls = depth_charts.urls().unwrap();
+            let weekly = df!(
+                "season" => [2024],
+                "club_code" => ["KC"],
+                "week" => [1],
+                "game_type" => ["REG"],
+                "depth_team" => [1],
+                "formation" => ["Offense"],
+                "gsis_id" => ["00-0033873"],
+                "position" => ["QB"],
+                "depth_position" => ["QB"],
+                "full_name" => ["Patrick Mahomes"]
+            )
+            .unwrap();
+            let daily = df!(
+                "dt" => ["2025-09-01T12:00:00Z"],
+                "team" => ["KC"],
+                "player_name" => ["Patrick Mahomes"],
+                "gsis_id" => ["00-0033873"],
+                "pos_grp" => ["Offense"],
+                "pos_abb" => ["QB"],
+                "pos_rank" => [1]
+            )
+            .unwrap();
+
+            let weekly = depth_charts.normalize(&urls[0], &weekly).unwrap().unwrap();
+            let daily = depth_charts.normalize(&urls[1], &daily).unwrap().unwrap();
+
+            let shared = |data: &DataFrame| {
+                data.select_columns([
+                    "season",
+                    "week",
+                    "dt",
+                    "team",
+                    "gsis_id",
+                    "player_name",
+                    "position_group",
+                    "position",
+                    "depth",
+                ])
+            };
+            assert_eq!(
+                DataFrame::new(shared(&weekly).unwrap()).unwrap().schema(),
+                DataFrame::new(shared(&daily).unwrap()).unwrap().schema()
+            );
+
+            let stacked = stack(vec![weekly, daily]).unwrap();
+            let seasons: Vec<Option<i32>> = stacked
+                .column("season")
+                .unwrap()
+                .i32()
+                .unwrap()
+                .into_iter()
+                .collect();
+            let weeks: Vec<Option<i32>> = stacked
+                .column("week")
+                .unwrap()
+                .i32()
+                .unwrap()
+                .into_iter()
+                .collect();
+
+            let game_types: Vec<Option<&str>> = stacked
+                .column("game_type")
+                .unwrap()
+                .str()
+                .unwrap()
+                .into_iter()
+                .collect();
+
+            assert_eq!(stacked.shape(), (2, 11));
+            assert_eq!(seasons, [Some(2024), Some(2025)]);
+            assert_eq!(weeks, [Some(1), None]);
+            assert_eq!(game_types, [Some("REG"), None]);
+            assert!(stacked.column("roster_position").is_ok());
+        }
+    }
+
+    mod injuries_downloader_tests {
+        use super::*;
+
+        #[test]
+        fn test_correct_urls_season_range() {
+            let base = "https://github.com/nflverse/nflverse-data/releases/download/injuries/";
+            let injuries = Injuries::new(Some(vec![2009, 2010])).with_format(FileFormat::Parquet);
+            let expected_urls = vec![
+                Url::parse(&format!("{}injuries_2009.parquet", base)).unwrap(),
+                Url::parse(&format!("{}injuries_2010.parquet", base)).unwrap(),
+            ];
+            assert_eq!(injuries.urls().unwrap(), expected_urls);
+        }
+
+        #[test]
+        fn test_seasons_before_2009() {
+            let injuries = Injuries::new(Some(vec![2008, 2009]));
+            assert!(matches!(injuries.urls(), Err(Error::InvalidSeason(_))));
+        }
     }
 }
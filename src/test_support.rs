@@ -0,0 +1,25 @@
+//! Fixtures shared by the tests of several modules.
+use crate::downloader::Downloader;
+use crate::error::Result;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use url::Url;
+use uuid::Uuid;
+
+/// Downloader for a fixed URL.
+#[derive(Debug, Clone)]
+pub(crate) struct UrlDownloader(pub(crate) Url);
+
+impl Downloader for UrlDownloader {
+    fn url(&self) -> Result<Url> {
+        Ok(self.0.clone())
+    }
+}
+
+/// Creates an empty directory unique to a test.
+pub(crate) fn test_dir() -> PathBuf {
+    let dir = env::temp_dir().join(format!("nflreadrs-test-{}", Uuid::new_v4()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
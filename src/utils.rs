@@ -19,6 +19,23 @@ impl Clock for RealClock {
     }
 }
 
+/// Last week of a season, the Super Bowl.
+pub(crate) const LAST_WEEK: i32 = 22;
+
+/// Start of a season, the Thursday following Labor Day EST.
+fn season_start(year: i32) -> DateTime<Tz> {
+    // Labor day is the first Monday in september.
+    let labor_day = NaiveDate::from_weekday_of_month_opt(year, 9, Weekday::Mon, 1).unwrap();
+
+    let n_thursday = if labor_day.day() >= 5 { 2 } else { 1 };
+
+    let season_start =
+        NaiveDate::from_weekday_of_month_opt(year, 9, Weekday::Thu, n_thursday).unwrap();
+    Eastern
+        .from_local_datetime(&season_start.and_hms_opt(0, 0, 0).unwrap())
+        .unwrap()
+}
+
 /// Private function to calculate current season.
 fn get_current_season_internal(roster: Option<bool>, clock: &impl Clock) -> i32 {
     let roster = roster.unwrap_or_default();
@@ -32,19 +49,22 @@ fn get_current_season_internal(roster: Option<bool>, clock: &impl Clock) -> i32
         if now >= march_15 { year } else { year - 1 }
     } else {
         // Season logic: current year after Thursday following Labor Day.
-        // Labor day is the first Monday in september.
-        let labor_day = NaiveDate::from_weekday_of_month_opt(year, 9, Weekday::Mon, 1).unwrap();
-
-        let n_thursday = if labor_day.day() >= 5 { 2 } else { 1 };
+        if now >= season_start(year) {
+            year
+        } else {
+            year - 1
+        }
+    }
+}
 
-        let season_start =
-            NaiveDate::from_weekday_of_month_opt(year, 9, Weekday::Thu, n_thursday).unwrap();
-        let season_start = Eastern
-            .from_local_datetime(&season_start.and_hms_opt(0, 0, 0).unwrap())
-            .unwrap();
+/// Private function to calculate current week.
+fn get_current_week_internal(clock: &impl Clock) -> i32 {
+    let season = get_current_season_internal(None, clock);
+    // Calendar days, elapsed time is off by an hour once daylight saving time ends.
+    let days = (clock.now().date_naive() - season_start(season).date_naive()).num_days();
 
-        if now >= season_start { year } else { year - 1 }
-    }
+    // Weeks start on Thursdays, the offseason counts as the last week of the previous season.
+    (days / 7 + 1).clamp(1, LAST_WEEK as i64) as i32
 }
 
 /// Gets the current season applying the approriate EST clock.
@@ -73,27 +93,45 @@ pub fn get_current_season(roster: Option<bool>) -> i32 {
     get_current_season_internal(roster, &RealClock)
 }
 
+/// Gets the current week of the current season applying the EST clock.
+///
+/// Weeks are counted from the Thursday following Labor Day, see `get_current_season`, and
+/// span seven days each. Until the next season starts, the last week of the season, 22, is returned.
+///
+/// # Examples
+///
+/// ```
+/// use nflreadrs::utils::get_current_week;
+///
+/// let current_week = get_current_week();
+/// assert!((1..=22).contains(&current_week));
+/// ```
+pub fn get_current_week() -> i32 {
+    get_current_week_internal(&RealClock)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    mod get_current_season_tests {
-        use super::*;
+    /// Clock fixed to the evening of a date.
+    struct FakeClock {
+        year: i32,
+        month: u32,
+        day: u32,
+    }
 
-        struct FakeClock {
-            year: i32,
-            month: u32,
-            day: u32,
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Tz> {
+            let day = NaiveDate::from_ymd_opt(self.year, self.month, self.day).unwrap();
+            Eastern
+                .from_local_datetime(&day.and_hms_opt(23, 35, 45).unwrap())
+                .unwrap()
         }
+    }
 
-        impl Clock for FakeClock {
-            fn now(&self) -> DateTime<Tz> {
-                let day = NaiveDate::from_ymd_opt(self.year, self.month, self.day).unwrap();
-                Eastern
-                    .from_local_datetime(&day.and_hms_opt(23, 35, 45).unwrap())
-                    .unwrap()
-            }
-        }
+    mod get_current_season_tests {
+        use super::*;
 
         #[test]
         fn test_roster_true_various_dates() {
@@ -149,4 +187,27 @@ mod tests {
             }
         }
     }
+
+    mod get_current_week_tests {
+        use super::*;
+
+        #[test]
+        fn test_various_dates() {
+            let cases = [
+                // (year, month, day, expected week)
+                (2025, 9, 4, 1),
+                (2025, 9, 10, 1),
+                (2025, 9, 11, 2),
+                (2025, 12, 31, 17),
+                (2026, 2, 8, 22),
+                (2026, 7, 1, 22),
+                (2026, 9, 10, 1),
+            ];
+
+            for (year, month, day, exp) in cases {
+                let fake_clock = FakeClock { year, month, day };
+                assert_eq!(get_current_week_internal(&fake_clock), exp);
+            }
+        }
+    }
 }